@@ -0,0 +1,32 @@
+pragma circom 2.1.6;
+
+include "circomlib/circuits/poseidon.circom";
+
+// Proves knowledge of `secret` such that poseidon(photoHash, secret) == commitment
+// without revealing the secret. Verified on-chain by `verify_snark_proof` in
+// the Stylus Verifier contract.
+//
+// Public signals (in order): photoHash, commitment, challenge
+//   photoHash  - SHA-256 photo hash reduced modulo the BN254 scalar field
+//   commitment - value stored as `zk_commitment` at verification time
+//   challenge  - `ownershipChallengeDigest(photoHash, challenge)` reduced
+//                modulo the scalar field; binds the proof to one challenge
+template PhotoOwnership() {
+    signal input photoHash;
+    signal input commitment;
+    signal input challenge;
+    signal input secret;
+
+    component hasher = Poseidon(2);
+    hasher.inputs[0] <== photoHash;
+    hasher.inputs[1] <== secret;
+
+    commitment === hasher.out;
+
+    // A public input outside every constraint could be swapped in an
+    // existing proof, so square the challenge into one
+    signal challengeSquare;
+    challengeSquare <== challenge * challenge;
+}
+
+component main {public [photoHash, commitment, challenge]} = PhotoOwnership();
@@ -0,0 +1,30 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Revert reasons raised by the Verifier and its companions alike.
+//! Each contract lists the ones it uses in its own error enum.
+
+use alloy_sol_types::sol;
+
+sol! {
+    // Photo hash has no attestation yet
+    error PhotoNotVerified(uint256 photo_hash);
+    // Caller is not the owner of the photo attestation
+    error NotPhotoOwner(uint256 photo_hash, address owner);
+    // Attestation has been revoked and can no longer change
+    error AttestationIsRevoked(uint256 photo_hash);
+    // ZK commitment is zero
+    error InvalidCommitment(uint256 photo_hash);
+    // Caller is not the contract owner
+    error NotOwner(address caller);
+    // Signature is malformed, malleable or unrecoverable
+    error InvalidSignature();
+    // Contract is paused
+    error EnforcedPause();
+    // Account lacks the role required for this call
+    error MissingRole(bytes32 role, address account);
+    // Hook called by something other than the Verifier
+    error NotVerifier(address caller);
+    // The Verifier reverted a companion's call with `reason`, its own revert data
+    error VerifierRejected(bytes reason);
+}
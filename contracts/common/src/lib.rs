@@ -0,0 +1,12 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Pieces shared by the ArbiPic Verifier and its companion contracts.
+
+extern crate alloc;
+
+pub mod errors;
+pub mod log;
+pub mod roles;
+pub mod signature;
+pub mod verifier;
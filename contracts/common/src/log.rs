@@ -0,0 +1,34 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Event emission for contracts close to the code size limit.
+//!
+//! `Host::log` unwraps the topic encoding, which links in the panic
+//! formatting of alloy's error type: several KB of compressed wasm per
+//! contract. Topics only fail to encode into a buffer that is too short,
+//! which the four-word buffer here never is.
+
+use alloc::vec::Vec;
+use alloy_sol_types::{abi::token::WordToken, SolEvent, TopicList};
+use stylus_sdk::prelude::*;
+
+/// Emit `event` from the current contract
+pub fn emit<H: Host + ?Sized, E: SolEvent>(host: &H, event: E) {
+    let mut topics = [WordToken::default(); 4];
+    if event.encode_topics_raw(&mut topics).is_err() {
+        return;
+    }
+    let mut data = Vec::new();
+    event.encode_data_to(&mut data);
+    emit_raw(host, &topics[..<E::TopicList as TopicList>::COUNT], &data);
+}
+
+/// Emit a log from encoded topics and data, shared by every event type
+fn emit_raw<H: Host + ?Sized>(host: &H, topics: &[WordToken], data: &[u8]) {
+    let mut bytes = Vec::with_capacity(32 * topics.len() + data.len());
+    for topic in topics {
+        bytes.extend_from_slice(topic.as_slice());
+    }
+    bytes.extend_from_slice(data);
+    host.emit_log(&bytes, topics.len());
+}
@@ -0,0 +1,38 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Role identifiers for delegated Verifier administration.
+//! Each id is `keccak256("<NAME>")`; the contract owner implicitly holds every role.
+
+use stylus_sdk::alloy_primitives::{b256, B256};
+
+/// Grants and revokes roles, toggles permissioned mode
+pub const ADMIN_ROLE: B256 = b256!("a49807205ce4d355092ef5a8a18f56e8913cf4a201fbe287825b095693c21775");
+/// Pauses and unpauses user-facing writes
+pub const PAUSER_ROLE: B256 = b256!("65d7a28e3265b37a6474929f336521b332c1681b933f6cb9f3376673440d862a");
+/// Revokes attestations on behalf of the platform
+pub const MODERATOR_ROLE: B256 = b256!("71f3d55856e4058ed06ee057d79ada615f65cdf5f9ee88181b914225088f834f");
+/// Grants and revokes the submitter role
+pub const ATTESTOR_MANAGER_ROLE: B256 = b256!("b14660b418a31c41c49910ea4e1ea1db721d1282b2cfaf9895cf50eab055bd8d");
+/// Enrolls and revokes trusted capture devices
+pub const DEVICE_MANAGER_ROLE: B256 = b256!("20c605fd0c297c12fdef1ea05fc5e693eeeda1f5ffb147a823a0db69c8bbea9e");
+/// May verify photos while permissioned mode is on
+pub const SUBMITTER_ROLE: B256 = b256!("e1a65d1a914580ff6931bc952f0fb26573e9282358a4458bceb9ccc6d923d041");
+/// Companion contract that records and moves photos on behalf of their owners
+pub const COMPANION_ROLE: B256 = b256!("e38cfb508fb0ea1f74eee16ff96be52d091242d49e11472293b68387d7ff69b4");
+
+/// Each known role with the role allowed to grant and revoke it
+const ADMINS: [(B256, B256); 7] = [
+    (ADMIN_ROLE, ADMIN_ROLE),
+    (PAUSER_ROLE, ADMIN_ROLE),
+    (MODERATOR_ROLE, ADMIN_ROLE),
+    (ATTESTOR_MANAGER_ROLE, ADMIN_ROLE),
+    (DEVICE_MANAGER_ROLE, ADMIN_ROLE),
+    (SUBMITTER_ROLE, ATTESTOR_MANAGER_ROLE),
+    (COMPANION_ROLE, ADMIN_ROLE),
+];
+
+/// Role allowed to grant and revoke `role`; `None` for unknown roles
+pub fn admin_of(role: B256) -> Option<B256> {
+    ADMINS.iter().find(|(known, _)| *known == role).map(|(_, admin)| *admin)
+}
@@ -0,0 +1,144 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Signature checks for EOAs via the `ecrecover` precompile, with an
+//! ERC-1271 fallback for smart-contract wallets such as Safe.
+
+use alloc::vec::Vec;
+use stylus_sdk::{
+    alloy_primitives::{address, uint, Address, B256, U256},
+    call::RawCall,
+    crypto::keccak,
+    prelude::*,
+};
+
+/// Address of the `ecrecover` precompile
+const ECRECOVER: Address = address!("0000000000000000000000000000000000000001");
+
+/// Half the secp256k1 group order; larger `s` values are malleable
+const HALF_ORDER: U256 =
+    uint!(0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF5D576E7357A4501DDFE92F46681B20A0_U256);
+
+/// Return value of a successful `isValidSignature(bytes32,bytes)`
+const ERC1271_MAGIC_VALUE: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
+sol_interface! {
+    interface IERC1271 {
+        function isValidSignature(bytes32 hash, bytes signature) external view returns (bytes4);
+    }
+}
+
+/// Domain tag mixed into every ownership challenge digest
+const OWNERSHIP_PROOF_TAG: &[u8] = b"ArbiPic ownership proof";
+
+/// Digest an owner signs to answer a verifier-supplied `challenge`
+/// Binds chain, contract and photo so an answer can't be replayed elsewhere
+pub fn ownership_challenge_digest(
+    chain_id: u64,
+    contract: Address,
+    photo_hash: U256,
+    challenge: B256,
+) -> B256 {
+    let mut data = Vec::with_capacity(OWNERSHIP_PROOF_TAG.len() + 32 + 20 + 32 + 32);
+    data.extend_from_slice(OWNERSHIP_PROOF_TAG);
+    data.extend_from_slice(&U256::from(chain_id).to_be_bytes::<32>());
+    data.extend_from_slice(contract.as_slice());
+    data.extend_from_slice(&photo_hash.to_be_bytes::<32>());
+    data.extend_from_slice(challenge.as_slice());
+    keccak(data)
+}
+
+/// Domain tag mixed into every device capture digest
+const DEVICE_CAPTURE_TAG: &[u8] = b"ArbiPic device capture";
+
+/// Digest a capture device signs for a photo it produced at `capture_time`
+/// Binds chain and contract so a capture signature can't be replayed elsewhere,
+/// and the submitter and commitment so it can't be front-run by someone else
+pub fn device_capture_digest(
+    chain_id: u64,
+    contract: Address,
+    owner: Address,
+    photo_hash: U256,
+    zk_commitment: U256,
+    capture_time: U256,
+) -> B256 {
+    let mut data = Vec::with_capacity(DEVICE_CAPTURE_TAG.len() + 32 + 20 + 20 + 32 + 32 + 32);
+    data.extend_from_slice(DEVICE_CAPTURE_TAG);
+    data.extend_from_slice(&U256::from(chain_id).to_be_bytes::<32>());
+    data.extend_from_slice(contract.as_slice());
+    data.extend_from_slice(owner.as_slice());
+    data.extend_from_slice(&photo_hash.to_be_bytes::<32>());
+    data.extend_from_slice(&zk_commitment.to_be_bytes::<32>());
+    data.extend_from_slice(&capture_time.to_be_bytes::<32>());
+    keccak(data)
+}
+
+/// Domain tag mixed into every passkey attestation challenge
+const PASSKEY_ATTESTATION_TAG: &[u8] = b"ArbiPic passkey attestation";
+
+/// WebAuthn challenge a passkey signs to attest `photo_hash` for `owner`
+/// Binds the submitter so a relayed assertion can't be claimed by someone else
+pub fn passkey_attestation_challenge(
+    chain_id: u64,
+    contract: Address,
+    owner: Address,
+    photo_hash: U256,
+    zk_commitment: U256,
+) -> B256 {
+    let mut data = Vec::with_capacity(PASSKEY_ATTESTATION_TAG.len() + 32 + 20 + 20 + 32 + 32);
+    data.extend_from_slice(PASSKEY_ATTESTATION_TAG);
+    data.extend_from_slice(&U256::from(chain_id).to_be_bytes::<32>());
+    data.extend_from_slice(contract.as_slice());
+    data.extend_from_slice(owner.as_slice());
+    data.extend_from_slice(&photo_hash.to_be_bytes::<32>());
+    data.extend_from_slice(&zk_commitment.to_be_bytes::<32>());
+    keccak(data)
+}
+
+/// Hash produced by `personal_sign` over a 32-byte message
+pub fn eth_signed_message_hash(message: B256) -> B256 {
+    let mut data = [0u8; 60];
+    data[..28].copy_from_slice(b"\x19Ethereum Signed Message:\n32");
+    data[28..].copy_from_slice(message.as_slice());
+    keccak(data)
+}
+
+/// Check that `signer` signed `digest`
+/// Contracts are asked via ERC-1271 `isValidSignature`; EOAs via `ecrecover`
+pub fn is_valid_signature_now<H: Host>(host: &H, signer: Address, digest: B256, signature: &[u8]) -> bool {
+    if host.code_size(signer) > 0 {
+        return IERC1271::new(signer)
+            .is_valid_signature(host, Call::new(), digest, signature.to_vec().into())
+            .is_ok_and(|magic| magic.0 == ERC1271_MAGIC_VALUE);
+    }
+    recover(host, digest, signature) == Some(signer)
+}
+
+/// Recover the signer of `digest` from a 65-byte `r || s || v` signature
+/// Returns `None` for malformed, malleable or unrecoverable signatures
+pub fn recover<H: Host + ?Sized>(host: &H, digest: B256, signature: &[u8]) -> Option<Address> {
+    if signature.len() != 65 {
+        return None;
+    }
+    if U256::from_be_slice(&signature[32..64]) > HALF_ORDER {
+        return None;
+    }
+    let v = match signature[64] {
+        0 | 1 => signature[64] + 27,
+        27 | 28 => signature[64],
+        _ => return None,
+    };
+
+    // Precompile input: digest || v (left padded) || r || s
+    let mut input = [0u8; 128];
+    input[..32].copy_from_slice(digest.as_slice());
+    input[63] = v;
+    input[64..].copy_from_slice(&signature[..64]);
+
+    let output = unsafe { RawCall::new_static(host).call(ECRECOVER, &input) }.ok()?;
+    if output.len() != 32 {
+        return None;
+    }
+    let signer = Address::from_slice(&output[12..]);
+    (signer != Address::ZERO).then_some(signer)
+}
@@ -0,0 +1,120 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! The Verifier as seen from its companion contracts.
+//!
+//! Companions read the registry through `IVerifier` and write to it through
+//! `recordPhoto` and `movePhoto`, once granted `COMPANION_ROLE`. Hooks added
+//! with `setHook` hear about every mint, transfer and revocation through
+//! `IPhotoHook`. Stylus contracts aren't reentrant, so a hook can't call back
+//! into the Verifier: everything it needs comes with the call.
+
+use alloc::vec::Vec;
+use stylus_sdk::{
+    alloy_primitives::{Address, B256, U256},
+    prelude::*,
+};
+
+use crate::errors::VerifierRejected;
+
+sol_interface! {
+    interface IVerifier {
+        function recordPhoto(address owner, uint256 photo_hash, uint256 zk_commitment, bytes cid) external returns (uint256);
+        function movePhoto(uint256 photo_hash, address from, address to) external;
+        function getAttestation(uint256 photo_hash) external view returns (uint256, address, uint256);
+        function getAttestationStatus(uint256 photo_hash) external view returns (uint8, uint256, uint8);
+        function isVerified(uint256 photo_hash) external view returns (bool);
+        function getOwnerOf(uint256 photo_hash) external view returns (address);
+        function getOwnerPhotoCount(address owner) external view returns (uint256);
+        function hasRole(bytes32 role, address account) external view returns (bool);
+        function getContractOwner() external view returns (address);
+        function isPermissionedMode() external view returns (bool);
+        function isPaused() external view returns (bool);
+    }
+}
+
+// Host and call config come on top of the five hook arguments
+#[allow(clippy::too_many_arguments)]
+mod hook {
+    use stylus_sdk::prelude::*;
+
+    sol_interface! {
+        interface IPhotoHook {
+            function onPhotoMoved(uint256 photo_hash, address from, address to, uint256 zk_commitment, bytes cid) external;
+        }
+    }
+}
+
+pub use hook::IPhotoHook;
+
+/// `getAttestationStatus` code of a revoked attestation
+const STATUS_REVOKED: u8 = 2;
+
+/// A photo's attestation as the Verifier reports it
+#[derive(Default)]
+pub struct Attestation {
+    pub verified_at: U256,
+    pub owner: Address,
+    pub zk_commitment: U256,
+    pub revoked_at: U256,
+}
+
+impl Attestation {
+    /// Verified and not revoked
+    pub fn is_active(&self) -> bool {
+        self.verified_at > U256::ZERO && self.revoked_at == U256::ZERO
+    }
+}
+
+/// Read the attestation of `photo_hash`; an unverified one if the call fails
+pub fn attestation<H: Host>(host: &H, verifier: Address, photo_hash: U256) -> Attestation {
+    let verifier = IVerifier::new(verifier);
+    let Ok((verified_at, owner, zk_commitment)) = verifier.get_attestation(host, Call::new(), photo_hash) else {
+        return Attestation::default();
+    };
+    let revoked_at = match verifier.get_attestation_status(host, Call::new(), photo_hash) {
+        Ok((STATUS_REVOKED, revoked_at, _)) => revoked_at,
+        Ok(_) => U256::ZERO,
+        Err(_) => return Attestation::default(),
+    };
+    Attestation { verified_at, owner, zk_commitment, revoked_at }
+}
+
+/// Check whether a photo is verified and not revoked; false if the call fails
+pub fn is_verified<H: Host>(host: &H, verifier: Address, photo_hash: U256) -> bool {
+    IVerifier::new(verifier).is_verified(host, Call::new(), photo_hash).unwrap_or(false)
+}
+
+/// Owner of an active attestation; `None` when it is missing, revoked or the call fails
+pub fn active_owner<H: Host>(host: &H, verifier: Address, photo_hash: U256) -> Option<Address> {
+    if !is_verified(host, verifier, photo_hash) {
+        return None;
+    }
+    IVerifier::new(verifier).get_owner_of(host, Call::new(), photo_hash).ok()
+}
+
+/// Owner of the Verifier contract; zero if the call fails
+pub fn contract_owner<H: Host>(host: &H, verifier: Address) -> Address {
+    IVerifier::new(verifier).get_contract_owner(host, Call::new()).unwrap_or_default()
+}
+
+/// Check a Verifier role, the Verifier owner holding them all
+pub fn has_role<H: Host>(host: &H, verifier: Address, role: B256, account: Address) -> bool {
+    IVerifier::new(verifier).has_role(host, Call::new(), role, account).unwrap_or(false)
+}
+
+/// Check whether `account` may submit photos, as in the Verifier's permissioned mode
+pub fn may_submit<H: Host>(host: &H, verifier: Address, account: Address) -> bool {
+    let permissioned = IVerifier::new(verifier).is_permissioned_mode(host, Call::new()).unwrap_or(true);
+    !permissioned || has_role(host, verifier, crate::roles::SUBMITTER_ROLE, account)
+}
+
+/// Check whether the Verifier is paused; assumed so if the call fails
+pub fn is_paused<H: Host>(host: &H, verifier: Address) -> bool {
+    IVerifier::new(verifier).is_paused(host, Call::new()).unwrap_or(true)
+}
+
+/// Wrap the revert data of a failed Verifier call for a companion's own error
+pub fn rejected(error: impl Into<Vec<u8>>) -> VerifierRejected {
+    VerifierRejected { reason: error.into().into() }
+}
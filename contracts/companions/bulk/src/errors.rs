@@ -0,0 +1,31 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Typed revert reasons for the Bulk contract.
+
+use alloy_sol_types::sol;
+use stylus_sdk::prelude::*;
+
+pub use arbipic_common::errors::{EnforcedPause, MissingRole, VerifierRejected};
+
+sol! {
+    // Batch arrays have different lengths
+    error LengthMismatch(uint256 hashes, uint256 commitments);
+    // Batch is empty or larger than the per-call limit
+    error InvalidBatchSize(uint256 size);
+    // Merkle root is zero or declares no leaves
+    error InvalidMerkleRoot(bytes32 merkle_root);
+    // Merkle root has already been registered
+    error MerkleRootAlreadyRegistered(bytes32 merkle_root, address owner);
+}
+
+#[derive(SolidityError)]
+pub enum BulkError {
+    LengthMismatch(LengthMismatch),
+    InvalidBatchSize(InvalidBatchSize),
+    InvalidMerkleRoot(InvalidMerkleRoot),
+    MerkleRootAlreadyRegistered(MerkleRootAlreadyRegistered),
+    EnforcedPause(EnforcedPause),
+    MissingRole(MissingRole),
+    VerifierRejected(VerifierRejected),
+}
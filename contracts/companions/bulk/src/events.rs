@@ -0,0 +1,11 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Logs emitted by the Bulk contract; batch verifications log on the Verifier.
+
+use alloy_sol_types::sol;
+
+sol! {
+    // A Merkle root anchoring many photo hashes was registered
+    event MerkleRootRegistered(bytes32 indexed merkle_root, address indexed owner, uint256 leaf_count, uint256 timestamp);
+}
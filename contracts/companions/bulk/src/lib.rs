@@ -0,0 +1,297 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Bulk verification for the Verifier: bursts of photos in one transaction,
+//! and whole archives anchored by a single Merkle root.
+//!
+//! Batch items are recorded on the Verifier one by one through `recordPhoto`,
+//! which needs `COMPANION_ROLE`. Merkle batches live here and are checked
+//! against the Verifier's individual attestations.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+
+extern crate alloc;
+
+mod errors;
+mod events;
+mod merkle;
+
+pub use errors::*;
+pub use events::*;
+use alloc::vec::Vec;
+use arbipic_common::{
+    log::emit,
+    verifier::{self, IVerifier},
+};
+use stylus_sdk::storage::*;
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, B256, U256},
+    prelude::*,
+};
+
+// One Merkle root anchoring many photo hashes at once
+#[storage]
+pub struct MerkleBatch {
+    registered_at: StorageU256,   // Block timestamp when the root was registered
+    owner: StorageAddress,        // Owner of every photo in the tree
+    leaf_count: StorageU256,      // Number of leaves, as declared by the owner
+}
+
+/// Per-item result codes returned by `verify_photos_batch`
+pub const BATCH_VERIFIED: u8 = 0;
+pub const BATCH_ALREADY_OWNED: u8 = 1;
+pub const BATCH_ALREADY_VERIFIED: u8 = 2;
+pub const BATCH_INVALID_COMMITMENT: u8 = 3;
+
+/// Largest burst accepted by `verify_photos_batch`
+pub const MAX_BATCH_SIZE: usize = 200;
+
+#[storage]
+#[entrypoint]
+pub struct Bulk {
+    // Verifier the batches are recorded on
+    verifier: StorageAddress,
+
+    // Batch attestations: merkleRoot => batch
+    merkle_batches: StorageMap<B256, MerkleBatch>,
+}
+
+#[public]
+impl Bulk {
+    /// Bind the contract to the Verifier it records photos on
+    #[constructor]
+    pub fn constructor(&mut self, verifier: Address) {
+        self.verifier.set(verifier);
+    }
+
+    /// Verify a burst of photos in one transaction
+    /// Applies the `verify_photo` rules per item but skips failures instead of
+    /// reverting; returns one `BATCH_*` result code per photo hash
+    pub fn verify_photos_batch(&mut self, photo_hashes: Vec<U256>, zk_commitments: Vec<U256>) -> Result<Vec<u8>, BulkError> {
+        if photo_hashes.len() != zk_commitments.len() {
+            return Err(BulkError::LengthMismatch(LengthMismatch {
+                hashes: U256::from(photo_hashes.len()),
+                commitments: U256::from(zk_commitments.len()),
+            }));
+        }
+        if photo_hashes.is_empty() || photo_hashes.len() > MAX_BATCH_SIZE {
+            return Err(BulkError::InvalidBatchSize(InvalidBatchSize { size: U256::from(photo_hashes.len()) }));
+        }
+
+        let sender = self.vm().msg_sender();
+        self.require_submitter(sender)?;
+        let verifier = self.verifier.get();
+        let mut results = Vec::with_capacity(photo_hashes.len());
+        for (photo_hash, zk_commitment) in photo_hashes.into_iter().zip(zk_commitments) {
+            if zk_commitment == U256::ZERO {
+                results.push(BATCH_INVALID_COMMITMENT);
+                continue;
+            }
+            let existing = verifier::attestation(self.vm(), verifier, photo_hash);
+            if let Some(result) = claimed_result(&existing, sender) {
+                results.push(result);
+                continue;
+            }
+            let config = Call::new_mutating(self);
+            IVerifier::new(verifier)
+                .record_photo(self.vm(), config, sender, photo_hash, zk_commitment, Bytes::default())
+                .map_err(|error| BulkError::VerifierRejected(verifier::rejected(error)))?;
+            results.push(BATCH_VERIFIED);
+        }
+        Ok(results)
+    }
+
+    /// Anchor a whole archive with one Merkle root of photo hashes
+    /// Leaves follow OpenZeppelin's `StandardMerkleTree` over `uint256`;
+    /// individual photos are later proved with `is_verified_in_batch`.
+    /// Leaves aren't checked against existing claims, so batches get no
+    /// first-claim protection: a photo attested individually to someone else
+    /// never counts as part of the batch
+    pub fn register_merkle_root(&mut self, merkle_root: B256, leaf_count: U256) -> Result<U256, BulkError> {
+        if merkle_root == B256::ZERO || leaf_count == U256::ZERO {
+            return Err(BulkError::InvalidMerkleRoot(InvalidMerkleRoot { merkle_root }));
+        }
+
+        let timestamp = U256::from(self.vm().block_timestamp());
+        let sender = self.vm().msg_sender();
+        self.require_submitter(sender)?;
+        let mut batch = self.merkle_batches.setter(merkle_root);
+        if batch.registered_at.get() > U256::ZERO {
+            return Err(BulkError::MerkleRootAlreadyRegistered(MerkleRootAlreadyRegistered {
+                merkle_root,
+                owner: batch.owner.get(),
+            }));
+        }
+        batch.registered_at.set(timestamp);
+        batch.owner.set(sender);
+        batch.leaf_count.set(leaf_count);
+
+        emit(self.vm(), MerkleRootRegistered { merkle_root, owner: sender, leaf_count, timestamp });
+        Ok(timestamp)
+    }
+
+    /// Check that a photo is included in a registered Merkle batch
+    /// False when the photo is individually attested to someone other than the batch owner
+    pub fn is_verified_in_batch(&self, merkle_root: B256, photo_hash: U256, proof: Vec<B256>) -> Result<bool, BulkError> {
+        let batch = self.merkle_batches.getter(merkle_root);
+        if batch.registered_at.get() == U256::ZERO {
+            return Ok(false);
+        }
+        let attestation = verifier::attestation(self.vm(), self.verifier.get(), photo_hash);
+        if attestation.verified_at > U256::ZERO && attestation.owner != batch.owner.get() {
+            return Ok(false);
+        }
+        Ok(merkle::process_proof(merkle::leaf_hash(photo_hash), &proof) == merkle_root)
+    }
+
+    /// Get the batch attestation covering a photo: `(registered_at, owner)`
+    /// Returns zeros unless `is_verified_in_batch` holds for the photo
+    pub fn get_batch_attestation(&self, merkle_root: B256, photo_hash: U256, proof: Vec<B256>) -> Result<(U256, Address), BulkError> {
+        if !self.is_verified_in_batch(merkle_root, photo_hash, proof)? {
+            return Ok((U256::ZERO, Address::ZERO));
+        }
+        let batch = self.merkle_batches.getter(merkle_root);
+        Ok((batch.registered_at.get(), batch.owner.get()))
+    }
+
+    /// Get a registered batch: `(registered_at, owner, leaf_count)`
+    pub fn get_merkle_batch(&self, merkle_root: B256) -> Result<(U256, Address, U256), BulkError> {
+        let batch = self.merkle_batches.getter(merkle_root);
+        Ok((batch.registered_at.get(), batch.owner.get(), batch.leaf_count.get()))
+    }
+
+    /// Get the Verifier batches are recorded on
+    pub fn get_verifier(&self) -> Result<Address, BulkError> {
+        Ok(self.verifier.get())
+    }
+}
+
+impl Bulk {
+    /// Revert while the Verifier is paused, or in its permissioned mode unless
+    /// `account` is a submitter
+    fn require_submitter(&self, account: Address) -> Result<(), BulkError> {
+        let verifier = self.verifier.get();
+        if verifier::is_paused(self.vm(), verifier) {
+            return Err(BulkError::EnforcedPause(EnforcedPause {}));
+        }
+        if !verifier::may_submit(self.vm(), verifier, account) {
+            return Err(BulkError::MissingRole(MissingRole { role: arbipic_common::roles::SUBMITTER_ROLE, account }));
+        }
+        Ok(())
+    }
+}
+
+/// Result code of a batch item whose hash is already attested; `None` when it is free
+fn claimed_result(existing: &verifier::Attestation, sender: Address) -> Option<u8> {
+    if existing.verified_at == U256::ZERO {
+        return None;
+    }
+    Some(if existing.owner == sender { BATCH_ALREADY_OWNED } else { BATCH_ALREADY_VERIFIED })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_sol_types::{sol, SolCall};
+    use stylus_sdk::testing::*;
+
+    sol! {
+        function recordPhoto(address owner, uint256 photo_hash, uint256 zk_commitment, bytes cid) external returns (uint256);
+        function isPaused() external view returns (bool);
+        function isPermissionedMode() external view returns (bool);
+        function hasRole(bytes32 role, address account) external view returns (bool);
+    }
+
+    const VERIFIER: Address = Address::repeat_byte(0xee);
+    const ALICE: Address = Address::repeat_byte(2);
+    const BOB: Address = Address::repeat_byte(3);
+    const COMMITMENT: U256 = U256::from_limbs([0xc0, 0, 0, 0]);
+
+    /// Bulk companion of `VERIFIER`, called by `ALICE`
+    fn deploy() -> (TestVM, Bulk) {
+        let vm = TestVM::default();
+        let mut contract = Bulk::from(&vm);
+        contract.constructor(VERIFIER);
+        vm.set_sender(ALICE);
+        (vm, contract)
+    }
+
+    /// Answer every Verifier call with `words`
+    /// TestVM returns the data of the last mock set from every call, so all
+    /// reads share one answer: all zeros is unpaused, open and unverified
+    fn answer(vm: &TestVM, words: &[U256]) {
+        let data = words.iter().flat_map(|word| word.to_be_bytes::<32>()).collect();
+        vm.mock_static_call(VERIFIER, isPausedCall {}.abi_encode(), Ok(data));
+    }
+
+    #[test]
+    fn batches_must_pair_hashes_with_commitments() {
+        let (_vm, mut contract) = deploy();
+
+        let mismatched = contract.verify_photos_batch(vec![U256::from(1)], vec![]);
+        assert!(matches!(mismatched, Err(BulkError::LengthMismatch(_))));
+        let empty = contract.verify_photos_batch(vec![], vec![]);
+        assert!(matches!(empty, Err(BulkError::InvalidBatchSize(_))));
+        let oversized = contract.verify_photos_batch(vec![U256::from(1); MAX_BATCH_SIZE + 1], vec![COMMITMENT; MAX_BATCH_SIZE + 1]);
+        assert!(matches!(oversized, Err(BulkError::InvalidBatchSize(_))));
+    }
+
+    #[test]
+    fn batches_get_one_result_per_photo() {
+        let (vm, mut contract) = deploy();
+        answer(&vm, &[U256::ZERO; 3]);
+
+        let results = contract.verify_photos_batch(
+            vec![U256::from(1), U256::from(2), U256::from(3)],
+            vec![COMMITMENT, U256::ZERO, COMMITMENT],
+        );
+        assert_eq!(results.ok(), Some(vec![BATCH_VERIFIED, BATCH_INVALID_COMMITMENT, BATCH_VERIFIED]));
+    }
+
+    #[test]
+    fn claimed_photos_are_skipped() {
+        let mut existing = verifier::Attestation { verified_at: U256::from(1000), owner: ALICE, ..Default::default() };
+        assert_eq!(claimed_result(&existing, ALICE), Some(BATCH_ALREADY_OWNED));
+        assert_eq!(claimed_result(&existing, BOB), Some(BATCH_ALREADY_VERIFIED));
+
+        // Revoked photos stay claimed
+        existing.revoked_at = U256::from(2000);
+        assert_eq!(claimed_result(&existing, BOB), Some(BATCH_ALREADY_VERIFIED));
+        assert_eq!(claimed_result(&verifier::Attestation::default(), ALICE), None);
+    }
+
+    #[test]
+    fn a_rejected_photo_reverts_the_batch() {
+        let (vm, mut contract) = deploy();
+        let call = recordPhotoCall { owner: ALICE, photo_hash: U256::from(2), zk_commitment: COMMITMENT, cid: Default::default() };
+        vm.mock_call(VERIFIER, call.abi_encode(), U256::ZERO, Err(vec![]));
+        answer(&vm, &[U256::ZERO; 3]);
+
+        let results = contract.verify_photos_batch(vec![U256::from(1), U256::from(2)], vec![COMMITMENT; 2]);
+        assert!(matches!(results, Err(BulkError::VerifierRejected(_))));
+    }
+
+    #[test]
+    fn batches_stop_while_the_verifier_is_paused() {
+        let (vm, mut contract) = deploy();
+        answer(&vm, &[U256::from(1)]);
+
+        let results = contract.verify_photos_batch(vec![U256::from(1)], vec![COMMITMENT]);
+        assert!(matches!(results, Err(BulkError::EnforcedPause(_))));
+    }
+
+    #[test]
+    fn permissioned_batches_need_a_submitter() {
+        let (vm, mut contract) = deploy();
+        vm.mock_static_call(VERIFIER, isPermissionedModeCall {}.abi_encode(), Err(vec![]));
+        let role = hasRoleCall { role: arbipic_common::roles::SUBMITTER_ROLE, account: ALICE };
+        vm.mock_static_call(VERIFIER, role.abi_encode(), Err(vec![]));
+        answer(&vm, &[U256::ZERO]);
+
+        let results = contract.verify_photos_batch(vec![U256::from(1)], vec![COMMITMENT]);
+        assert!(matches!(results, Err(BulkError::MissingRole(MissingRole { account: ALICE, .. }))));
+        let registered = contract.register_merkle_root(B256::repeat_byte(1), U256::from(4));
+        assert!(matches!(registered, Err(BulkError::MissingRole(_))));
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Merkle inclusion proofs for batch attestations.
+//!
+//! Compatible with OpenZeppelin's `StandardMerkleTree` over `["uint256"]`:
+//! leaves are double hashed and inner nodes hash sorted pairs, so proofs
+//! don't carry left/right positions.
+
+use stylus_sdk::{
+    alloy_primitives::{B256, U256},
+    crypto::keccak,
+};
+
+/// Leaf for a photo hash: `keccak256(keccak256(abi.encode(photo_hash)))`
+pub fn leaf_hash(photo_hash: U256) -> B256 {
+    keccak(keccak(photo_hash.to_be_bytes::<32>()))
+}
+
+/// Hash a proof up from `leaf` and return the resulting root
+pub fn process_proof(leaf: B256, proof: &[B256]) -> B256 {
+    proof.iter().fold(leaf, |node, sibling| hash_pair(node, *sibling))
+}
+
+/// Hash two nodes in sorted order
+fn hash_pair(a: B256, b: B256) -> B256 {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = [0u8; 64];
+    data[..32].copy_from_slice(first.as_slice());
+    data[32..].copy_from_slice(second.as_slice());
+    keccak(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::alloy_primitives::b256;
+
+    // `StandardMerkleTree.of([[1], [2], [3]], ["uint256"])`
+    const ROOT: B256 = b256!("6115218373392d12cc18a3d50ad2a53a2c99b391060070dfc0ebbbbe158ec944");
+    const LEAF_1: B256 = b256!("b5d9d894133a730aa651ef62d26b0ffa846233c74177a591a4a896adfda97d22");
+    const LEAF_2: B256 = b256!("1ab0c6948a275349ae45a06aad66a8bd65ac18074615d53676c09b67809099e0");
+    const LEAF_3: B256 = b256!("2584db4a68aa8b172f70bc04e2e74541617c003374de6eb4b295e823e5beab01");
+    const NODE_23: B256 = b256!("673620737675e2755ce8269a99904022d15da8d5843f5aec205cd243ff80240a");
+
+    #[test]
+    fn leaf_hash_matches_standard_merkle_tree() {
+        assert_eq!(leaf_hash(U256::from(1)), LEAF_1);
+        assert_eq!(leaf_hash(U256::from(2)), LEAF_2);
+        assert_eq!(leaf_hash(U256::from(3)), LEAF_3);
+    }
+
+    #[test]
+    fn proofs_reach_standard_merkle_tree_root() {
+        assert_eq!(process_proof(LEAF_1, &[NODE_23]), ROOT);
+        assert_eq!(process_proof(LEAF_2, &[LEAF_3, LEAF_1]), ROOT);
+        assert_eq!(process_proof(LEAF_3, &[LEAF_2, LEAF_1]), ROOT);
+    }
+
+    #[test]
+    fn proof_for_another_leaf_fails() {
+        assert_ne!(process_proof(leaf_hash(U256::from(4)), &[NODE_23]), ROOT);
+        assert_ne!(process_proof(LEAF_2, &[NODE_23]), ROOT);
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Typed revert reasons for the Devices contract.
+
+use alloy_sol_types::sol;
+use stylus_sdk::prelude::*;
+
+pub use arbipic_common::errors::{InvalidCommitment, InvalidSignature, MissingRole, NotVerifier, VerifierRejected};
+
+sol! {
+    // Device key is the zero address
+    error InvalidDevice(address device);
+    // Device key was never enrolled
+    error DeviceNotEnrolled(address device);
+    // Device key is already enrolled (or was, and has been revoked)
+    error DeviceAlreadyEnrolled(address device);
+    // Device key has been revoked
+    error DeviceIsRevoked(address device);
+    // Capture time is zero or in the future
+    error InvalidCaptureTime(uint256 capture_time);
+}
+
+#[derive(SolidityError)]
+pub enum DevicesError {
+    InvalidCommitment(InvalidCommitment),
+    InvalidSignature(InvalidSignature),
+    MissingRole(MissingRole),
+    InvalidDevice(InvalidDevice),
+    DeviceNotEnrolled(DeviceNotEnrolled),
+    DeviceAlreadyEnrolled(DeviceAlreadyEnrolled),
+    DeviceIsRevoked(DeviceIsRevoked),
+    InvalidCaptureTime(InvalidCaptureTime),
+    NotVerifier(NotVerifier),
+    VerifierRejected(VerifierRejected),
+}
@@ -0,0 +1,17 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Logs emitted by the Devices contract; the attestations themselves log on the Verifier.
+
+use alloy_sol_types::sol;
+
+sol! {
+    // A photo was verified with a capture device signature
+    event PhotoCaptured(uint256 indexed photo_hash, address indexed device, uint256 capture_time);
+    // A capture device key was enrolled
+    event DeviceEnrolled(address indexed device, address indexed sender);
+    // A capture device key was revoked
+    event DeviceRevoked(address indexed device, address indexed sender);
+    // A photo's WebAuthn passkey was set
+    event PasskeyUpdated(uint256 indexed photo_hash, address indexed owner, uint256 x, uint256 y);
+}
@@ -0,0 +1,311 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Hardware-backed verification for the Verifier: photos signed by enrolled
+//! capture devices, and photos approved by WebAuthn passkeys.
+//!
+//! Photos are recorded through the Verifier's `recordPhoto`, which needs
+//! `COMPANION_ROLE`. As a Verifier hook, the contract drops a photo's passkey
+//! once it changes hands or is revoked.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+
+extern crate alloc;
+
+mod errors;
+mod events;
+mod passkey;
+
+pub use errors::*;
+pub use events::*;
+use arbipic_common::{
+    log::emit,
+    roles::DEVICE_MANAGER_ROLE,
+    signature,
+    verifier::{self, IVerifier},
+};
+use stylus_sdk::storage::*;
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, B256, U256},
+    prelude::*,
+};
+
+// A trusted capture device, keyed by its signing address
+#[storage]
+pub struct Device {
+    enrolled_at: StorageU256,     // Block timestamp when the key was enrolled
+    revoked_at: StorageU256,      // Block timestamp when revoked, zero while trusted
+}
+
+// Hardware record of one photo
+#[storage]
+pub struct PhotoRecord {
+    device: StorageAddress,       // Enrolled capture device that signed the photo
+    captured_at: StorageU256,     // Capture time signed by the device
+    passkey_x: StorageU256,       // P-256 passkey answering ownership challenges
+    passkey_y: StorageU256,
+}
+
+#[storage]
+#[entrypoint]
+pub struct Devices {
+    // Verifier the photos are recorded on
+    verifier: StorageAddress,
+
+    // Capture device registry: device key => enrollment
+    devices: StorageMap<Address, Device>,
+
+    // Device and passkey records: photoHash => record
+    photos: StorageMap<U256, PhotoRecord>,
+}
+
+#[public]
+impl Devices {
+    /// Bind the contract to the Verifier it records photos on
+    #[constructor]
+    pub fn constructor(&mut self, verifier: Address) {
+        self.verifier.set(verifier);
+    }
+
+    /// Verifier hook: clear the passkey of a photo that changed hands or was revoked
+    pub fn on_photo_moved(
+        &mut self,
+        photo_hash: U256,
+        from: Address,
+        _to: Address,
+        _zk_commitment: U256,
+        _cid: Bytes,
+    ) -> Result<(), DevicesError> {
+        let sender = self.vm().msg_sender();
+        if sender != self.verifier.get() {
+            return Err(DevicesError::NotVerifier(NotVerifier { caller: sender }));
+        }
+        if from != Address::ZERO {
+            let mut record = self.photos.setter(photo_hash);
+            record.passkey_x.set(U256::ZERO);
+            record.passkey_y.set(U256::ZERO);
+        }
+        Ok(())
+    }
+
+    /// Verify a photo signed by an enrolled capture device
+    /// The device signs `device_capture_digest(caller, photo_hash, zk_commitment, capture_time)`
+    /// with `personal_sign`; the caller becomes the owner and the device is recorded
+    pub fn verify_photo_from_device(
+        &mut self,
+        photo_hash: U256,
+        zk_commitment: U256,
+        device: Address,
+        capture_time: U256,
+        signature: Bytes,
+    ) -> Result<U256, DevicesError> {
+        if zk_commitment == U256::ZERO {
+            return Err(DevicesError::InvalidCommitment(InvalidCommitment { photo_hash }));
+        }
+        if capture_time == U256::ZERO || capture_time > U256::from(self.vm().block_timestamp()) {
+            return Err(DevicesError::InvalidCaptureTime(InvalidCaptureTime { capture_time }));
+        }
+        self.require_active_device(device)?;
+
+        let sender = self.vm().msg_sender();
+        let digest = self.device_capture_digest(sender, photo_hash, zk_commitment, capture_time)?;
+        let digest = signature::eth_signed_message_hash(digest);
+        if !signature::is_valid_signature_now(self.vm(), device, digest, &signature) {
+            return Err(DevicesError::InvalidSignature(InvalidSignature {}));
+        }
+
+        let timestamp = self.record_photo(sender, photo_hash, zk_commitment)?;
+        let mut record = self.photos.setter(photo_hash);
+        record.device.set(device);
+        record.captured_at.set(capture_time);
+
+        emit(self.vm(), PhotoCaptured { photo_hash, device, capture_time });
+        Ok(timestamp)
+    }
+
+    /// Digest a device must sign to vouch for capturing `photo_hash` at `capture_time`
+    /// for `owner`, who submits it with `zk_commitment`
+    pub fn device_capture_digest(
+        &self,
+        owner: Address,
+        photo_hash: U256,
+        zk_commitment: U256,
+        capture_time: U256,
+    ) -> Result<B256, DevicesError> {
+        Ok(signature::device_capture_digest(
+            self.vm().chain_id(),
+            self.vm().contract_address(),
+            owner,
+            photo_hash,
+            zk_commitment,
+            capture_time,
+        ))
+    }
+
+    /// Get the capture device of a photo: `(device, captured_at)`
+    pub fn get_photo_device(&self, photo_hash: U256) -> Result<(Address, U256), DevicesError> {
+        let record = self.photos.getter(photo_hash);
+        Ok((record.device.get(), record.captured_at.get()))
+    }
+
+    /// Enroll a capture device key; needs the Verifier's `DEVICE_MANAGER_ROLE`
+    /// Revoked keys stay revoked; a re-keyed device enrolls its new key
+    pub fn enroll_device(&mut self, device: Address) -> Result<(), DevicesError> {
+        let sender = self.only_device_manager()?;
+        if device == Address::ZERO {
+            return Err(DevicesError::InvalidDevice(InvalidDevice { device }));
+        }
+        let timestamp = U256::from(self.vm().block_timestamp());
+        let mut entry = self.devices.setter(device);
+        if entry.enrolled_at.get() > U256::ZERO {
+            return Err(DevicesError::DeviceAlreadyEnrolled(DeviceAlreadyEnrolled { device }));
+        }
+        entry.enrolled_at.set(timestamp);
+
+        emit(self.vm(), DeviceEnrolled { device, sender });
+        Ok(())
+    }
+
+    /// Revoke a compromised or retired device key
+    /// Photos it already signed keep their attestation and device record
+    pub fn revoke_device(&mut self, device: Address) -> Result<(), DevicesError> {
+        let sender = self.only_device_manager()?;
+        self.require_active_device(device)?;
+        let timestamp = U256::from(self.vm().block_timestamp());
+        self.devices.setter(device).revoked_at.set(timestamp);
+
+        emit(self.vm(), DeviceRevoked { device, sender });
+        Ok(())
+    }
+
+    /// Get a device enrollment: `(enrolled_at, revoked_at)`
+    pub fn get_device(&self, device: Address) -> Result<(U256, U256), DevicesError> {
+        let entry = self.devices.getter(device);
+        Ok((entry.enrolled_at.get(), entry.revoked_at.get()))
+    }
+
+    /// Check if a device key is enrolled and not revoked
+    pub fn is_device_active(&self, device: Address) -> Result<bool, DevicesError> {
+        let entry = self.devices.getter(device);
+        Ok(entry.enrolled_at.get() > U256::ZERO && entry.revoked_at.get() == U256::ZERO)
+    }
+
+    /// Verify a photo approved by a WebAuthn passkey
+    /// The passkey signs `passkey_attestation_challenge(photo_hash, zk_commitment)`
+    /// for the caller, who becomes the owner; the passkey is kept to answer
+    /// ownership challenges through `verify_passkey_proof`
+    pub fn verify_photo_with_passkey(
+        &mut self,
+        photo_hash: U256,
+        zk_commitment: U256,
+        public_key: [U256; 2],
+        authenticator_data: Bytes,
+        client_data_json: Bytes,
+        signature: [U256; 2],
+    ) -> Result<U256, DevicesError> {
+        if zk_commitment == U256::ZERO {
+            return Err(DevicesError::InvalidCommitment(InvalidCommitment { photo_hash }));
+        }
+
+        let sender = self.vm().msg_sender();
+        let challenge = self.passkey_attestation_challenge(sender, photo_hash, zk_commitment)?;
+        if !passkey::verify_assertion(self.vm(), challenge, &authenticator_data, &client_data_json, &signature, &public_key) {
+            return Err(DevicesError::InvalidSignature(InvalidSignature {}));
+        }
+
+        let timestamp = self.record_photo(sender, photo_hash, zk_commitment)?;
+        let mut record = self.photos.setter(photo_hash);
+        record.passkey_x.set(public_key[0]);
+        record.passkey_y.set(public_key[1]);
+
+        emit(self.vm(), PasskeyUpdated { photo_hash, owner: sender, x: public_key[0], y: public_key[1] });
+        Ok(timestamp)
+    }
+
+    /// WebAuthn challenge a passkey signs to attest `photo_hash` for `owner`
+    pub fn passkey_attestation_challenge(&self, owner: Address, photo_hash: U256, zk_commitment: U256) -> Result<B256, DevicesError> {
+        Ok(signature::passkey_attestation_challenge(
+            self.vm().chain_id(),
+            self.vm().contract_address(),
+            owner,
+            photo_hash,
+            zk_commitment,
+        ))
+    }
+
+    /// Verify a passkey-based proof of ownership
+    /// The verifier picks a fresh `challenge`; the photo's passkey answers with a
+    /// WebAuthn assertion whose challenge is `ownership_challenge_digest`.
+    /// Revoked photos never pass
+    pub fn verify_passkey_proof(
+        &self,
+        photo_hash: U256,
+        challenge: B256,
+        authenticator_data: Bytes,
+        client_data_json: Bytes,
+        signature: [U256; 2],
+    ) -> Result<bool, DevicesError> {
+        let record = self.photos.getter(photo_hash);
+        let public_key = [record.passkey_x.get(), record.passkey_y.get()];
+        if public_key == [U256::ZERO; 2] || !verifier::is_verified(self.vm(), self.verifier.get(), photo_hash) {
+            return Ok(false);
+        }
+
+        let digest = self.ownership_challenge_digest(photo_hash, challenge)?;
+        Ok(passkey::verify_assertion(self.vm(), digest, &authenticator_data, &client_data_json, &signature, &public_key))
+    }
+
+    /// Digest the passkey must sign to answer `challenge` for `photo_hash`
+    pub fn ownership_challenge_digest(&self, photo_hash: U256, challenge: B256) -> Result<B256, DevicesError> {
+        Ok(signature::ownership_challenge_digest(
+            self.vm().chain_id(),
+            self.vm().contract_address(),
+            photo_hash,
+            challenge,
+        ))
+    }
+
+    /// Get the passkey of a photo as `(x, y)`; zero when it has none
+    pub fn get_passkey(&self, photo_hash: U256) -> Result<(U256, U256), DevicesError> {
+        let record = self.photos.getter(photo_hash);
+        Ok((record.passkey_x.get(), record.passkey_y.get()))
+    }
+
+    /// Get the Verifier photos are recorded on
+    pub fn get_verifier(&self) -> Result<Address, DevicesError> {
+        Ok(self.verifier.get())
+    }
+}
+
+impl Devices {
+    /// Record a photo for `owner` on the Verifier; returns its timestamp
+    fn record_photo(&mut self, owner: Address, photo_hash: U256, zk_commitment: U256) -> Result<U256, DevicesError> {
+        let verifier = self.verifier.get();
+        let config = Call::new_mutating(self);
+        IVerifier::new(verifier)
+            .record_photo(self.vm(), config, owner, photo_hash, zk_commitment, Bytes::default())
+            .map_err(|error| DevicesError::VerifierRejected(verifier::rejected(error)))
+    }
+
+    /// Revert unless the caller holds the Verifier's `DEVICE_MANAGER_ROLE`; returns the caller
+    fn only_device_manager(&self) -> Result<Address, DevicesError> {
+        let sender = self.vm().msg_sender();
+        if !verifier::has_role(self.vm(), self.verifier.get(), DEVICE_MANAGER_ROLE, sender) {
+            return Err(DevicesError::MissingRole(MissingRole { role: DEVICE_MANAGER_ROLE, account: sender }));
+        }
+        Ok(sender)
+    }
+
+    /// Revert unless `device` is enrolled and not revoked
+    fn require_active_device(&self, device: Address) -> Result<(), DevicesError> {
+        let entry = self.devices.getter(device);
+        if entry.enrolled_at.get() == U256::ZERO {
+            return Err(DevicesError::DeviceNotEnrolled(DeviceNotEnrolled { device }));
+        }
+        if entry.revoked_at.get() > U256::ZERO {
+            return Err(DevicesError::DeviceIsRevoked(DeviceIsRevoked { device }));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! secp256r1 (P-256) signatures and WebAuthn assertions, for passkeys held
+//! in browser and phone secure enclaves.
+//!
+//! Signatures are checked with the RIP-7212 `P256VERIFY` precompile, which
+//! Arbitrum chains provide. A public key is `[x, y]` and a signature `[r, s]`,
+//! both as big-endian words.
+
+use alloc::vec::Vec;
+use stylus_sdk::{
+    alloy_primitives::{address, Address, B256, U256},
+    call::RawCall,
+    prelude::*,
+};
+
+/// RIP-7212 `P256VERIFY` precompile
+const P256_VERIFY: Address = address!("0000000000000000000000000000000000000100");
+/// SHA-256 precompile
+const SHA256: Address = address!("0000000000000000000000000000000000000002");
+
+/// Authenticator data flag: user present
+const FLAG_USER_PRESENT: u8 = 0x01;
+/// Authenticator data flag: user verified (PIN or biometrics)
+const FLAG_USER_VERIFIED: u8 = 0x04;
+/// `rpIdHash (32) || flags (1) || signCount (4)`
+const MIN_AUTHENTICATOR_DATA_LEN: usize = 37;
+
+/// Client data type of an assertion made with `navigator.credentials.get`
+const CLIENT_DATA_TYPE: &[u8] = br#""type":"webauthn.get""#;
+
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Check a P-256 signature over a 32-byte digest
+/// High-`s` signatures are accepted, as authenticators don't normalize them
+pub fn verify<H: Host + ?Sized>(host: &H, digest: B256, signature: &[U256; 2], public_key: &[U256; 2]) -> bool {
+    // Precompile input: digest || r || s || x || y
+    let mut input = Vec::with_capacity(160);
+    input.extend_from_slice(digest.as_slice());
+    for word in signature.iter().chain(public_key) {
+        input.extend_from_slice(&word.to_be_bytes::<32>());
+    }
+
+    // Success is a 32-byte 1; a failed check returns nothing
+    match unsafe { RawCall::new_static(host).call(P256_VERIFY, &input) } {
+        Ok(output) => output.len() == 32 && output[31] == 1,
+        Err(_) => false,
+    }
+}
+
+/// Check a WebAuthn assertion whose challenge is `challenge`
+/// The authenticator signs `sha256(authenticator_data || sha256(client_data_json))`;
+/// the client data must be a `webauthn.get` over the base64url challenge, and
+/// the authenticator must report both user presence and user verification
+pub fn verify_assertion<H: Host + ?Sized>(
+    host: &H,
+    challenge: B256,
+    authenticator_data: &[u8],
+    client_data_json: &[u8],
+    signature: &[U256; 2],
+    public_key: &[U256; 2],
+) -> bool {
+    if authenticator_data.len() < MIN_AUTHENTICATOR_DATA_LEN {
+        return false;
+    }
+    let required = FLAG_USER_PRESENT | FLAG_USER_VERIFIED;
+    if authenticator_data[32] & required != required {
+        return false;
+    }
+
+    if !contains(client_data_json, CLIENT_DATA_TYPE) {
+        return false;
+    }
+    let mut expected = Vec::with_capacity(13 + 43 + 1);
+    expected.extend_from_slice(br#""challenge":""#);
+    expected.extend_from_slice(&base64url(challenge.as_slice()));
+    expected.push(b'"');
+    if !contains(client_data_json, &expected) {
+        return false;
+    }
+
+    let Some(client_data_hash) = sha256(host, client_data_json) else {
+        return false;
+    };
+    let mut message = Vec::with_capacity(authenticator_data.len() + 32);
+    message.extend_from_slice(authenticator_data);
+    message.extend_from_slice(client_data_hash.as_slice());
+    let Some(digest) = sha256(host, &message) else {
+        return false;
+    };
+    verify(host, digest, signature, public_key)
+}
+
+fn sha256<H: Host + ?Sized>(host: &H, data: &[u8]) -> Option<B256> {
+    let output = unsafe { RawCall::new_static(host).call(SHA256, data) }.ok()?;
+    (output.len() == 32).then(|| B256::from_slice(&output))
+}
+
+/// Unpadded base64url, as used for the WebAuthn challenge
+fn base64url(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..=chunk.len() {
+            out.push(BASE64URL[(n >> (18 - 6 * i) & 0x3f) as usize]);
+        }
+    }
+    out
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64url_is_unpadded() {
+        assert_eq!(base64url(b""), b"");
+        assert_eq!(base64url(b"f"), b"Zg");
+        assert_eq!(base64url(b"fo"), b"Zm8");
+        assert_eq!(base64url(b"foo"), b"Zm9v");
+    }
+
+    #[test]
+    fn base64url_uses_url_safe_alphabet() {
+        assert_eq!(base64url(&[0xf8, 0xf9, 0xfa, 0xfb, 0xfc, 0xfd, 0xfe, 0xff]), b"-Pn6-_z9_v8");
+    }
+
+    #[test]
+    fn base64url_encodes_a_challenge_like_webauthn() {
+        // `clientDataJSON.challenge` for a 32-byte challenge has 43 characters
+        let challenge: [u8; 32] = stylus_sdk::alloy_primitives::hex!(
+            "218296682d2cea5011dc1143a1712a327acd913b73a2ecb5ae5bd797560d1be8"
+        );
+        assert_eq!(base64url(&challenge), b"IYKWaC0s6lAR3BFDoXEqMnrNkTtzouy1rlvXl1YNG-g");
+    }
+}
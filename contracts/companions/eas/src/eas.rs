@@ -0,0 +1,52 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Ethereum Attestation Service records mirroring each verification.
+//!
+//! Attestations use the frontend's `PHOTO_VERIFICATION_SCHEMA`:
+//! `bytes32 photoHash, uint256 timestamp, address photographer, string ipfsCid, bytes32 zkCommitment`.
+
+use alloc::string::String;
+use alloy_sol_types::SolValue;
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, B256, U256},
+    prelude::*,
+};
+
+sol_interface! {
+    interface IEAS {
+        // attest(AttestationRequest) with AttestationRequest = (schema, (recipient,
+        // expirationTime, revocable, refUID, data, value))
+        function attest((bytes32, (address, uint64, bool, bytes32, bytes, uint256)) request) external payable returns (bytes32);
+        // revoke(RevocationRequest) with RevocationRequest = (schema, (uid, value))
+        function revoke((bytes32, (bytes32, uint256)) request) external payable;
+    }
+}
+
+/// Never-expiring, revocable attestation request for a verified photo
+/// `ref_uid` points a re-issued record at the one it replaces
+pub fn attestation_request(
+    schema: B256,
+    photo_hash: U256,
+    timestamp: U256,
+    photographer: Address,
+    ipfs_cid: String,
+    zk_commitment: U256,
+    ref_uid: B256,
+) -> (B256, (Address, u64, bool, B256, Bytes, U256)) {
+    let data = (
+        B256::from(photo_hash),
+        timestamp,
+        photographer,
+        ipfs_cid,
+        B256::from(zk_commitment),
+    )
+        .abi_encode_params();
+    (schema, (photographer, 0, true, ref_uid, data.into(), U256::ZERO))
+}
+
+/// Request revoking attestation `uid` made under `schema`
+pub fn revocation_request(schema: B256, uid: B256) -> (B256, (B256, U256)) {
+    (schema, (uid, U256::ZERO))
+}
@@ -0,0 +1,24 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Typed revert reasons for the EAS mirror.
+
+use alloy_sol_types::sol;
+use stylus_sdk::prelude::*;
+
+pub use arbipic_common::errors::{NotOwner, NotVerifier};
+
+sol! {
+    // EAS contract has no code or the schema UID is missing
+    error InvalidEasConfig(address eas, bytes32 schema);
+    // EAS rejected the mirroring attestation
+    error EasAttestationFailed(uint256 photo_hash);
+}
+
+#[derive(SolidityError)]
+pub enum EasError {
+    NotOwner(NotOwner),
+    NotVerifier(NotVerifier),
+    InvalidEasConfig(InvalidEasConfig),
+    EasAttestationFailed(EasAttestationFailed),
+}
@@ -0,0 +1,17 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Logs emitted by the EAS mirror.
+
+use alloy_sol_types::sol;
+
+sol! {
+    // A photo was mirrored into EAS
+    event EasAttested(uint256 indexed photo_hash, bytes32 uid);
+    // A photo's EAS attestation was revoked with it or re-issued on transfer
+    event EasRevoked(uint256 indexed photo_hash, bytes32 uid);
+    // EAS refused to revoke a photo's attestation, e.g. one from an earlier EAS config
+    event EasRevocationFailed(uint256 indexed photo_hash, bytes32 uid);
+    // The EAS mirror settings changed
+    event EasConfigUpdated(address indexed eas, bytes32 schema, address indexed sender);
+}
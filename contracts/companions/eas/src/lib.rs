@@ -0,0 +1,181 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Ethereum Attestation Service mirror of the Verifier's photos.
+//!
+//! A Verifier hook: once the Verifier owner sets an EAS contract and schema,
+//! every verification creates an EAS record, revocation revokes it and a
+//! transfer re-issues it to the new owner. A record EAS rejects reverts the
+//! Verifier change with it, so the two never drift. Photos verified before
+//! the hook was added with `setHook` aren't mirrored.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+
+extern crate alloc;
+
+mod eas;
+mod errors;
+mod events;
+
+pub use errors::*;
+pub use events::*;
+use alloc::string::String;
+use arbipic_common::{log::emit, verifier};
+use stylus_sdk::storage::*;
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, B256, U256},
+    prelude::*,
+};
+
+// What the EAS record of one photo is built from
+#[storage]
+pub struct EasRecord {
+    uid: StorageB256,             // UID of the current EAS attestation
+    verified_at: StorageU256,     // Block timestamp when the photo was verified
+    cid: StorageBytes,            // IPFS CID given at verification, if any
+}
+
+#[storage]
+#[entrypoint]
+pub struct Eas {
+    // Verifier whose photos are mirrored
+    verifier: StorageAddress,
+
+    // EAS contract mirrored to; zero disables the mirror
+    eas: StorageAddress,
+
+    // Registered UID of `PHOTO_VERIFICATION_SCHEMA` on `eas`
+    schema: StorageB256,
+
+    // Mirrored photos: photoHash => record
+    records: StorageMap<U256, EasRecord>,
+}
+
+#[public]
+impl Eas {
+    /// Bind the mirror to the Verifier whose photos it mirrors
+    #[constructor]
+    pub fn constructor(&mut self, verifier: Address) {
+        self.verifier.set(verifier);
+    }
+
+    /// Verifier hook: attest a new photo, re-issue a transferred one and revoke a revoked one
+    pub fn on_photo_moved(
+        &mut self,
+        photo_hash: U256,
+        from: Address,
+        to: Address,
+        zk_commitment: U256,
+        cid: Bytes,
+    ) -> Result<(), EasError> {
+        let sender = self.vm().msg_sender();
+        if sender != self.verifier.get() {
+            return Err(EasError::NotVerifier(NotVerifier { caller: sender }));
+        }
+
+        if from == Address::ZERO {
+            let timestamp = U256::from(self.vm().block_timestamp());
+            let mut record = self.records.setter(photo_hash);
+            record.verified_at.set(timestamp);
+            record.cid.set_bytes(&cid);
+            return self.attest(photo_hash, to, zk_commitment, B256::ZERO);
+        }
+
+        let previous = self.records.getter(photo_hash).uid.get();
+        if previous == B256::ZERO {
+            return Ok(());
+        }
+        self.revoke(photo_hash, previous);
+        if to == Address::ZERO {
+            self.records.setter(photo_hash).uid.set(B256::ZERO);
+            return Ok(());
+        }
+        self.attest(photo_hash, to, zk_commitment, previous)
+    }
+
+    /// Mirror attestations into EAS under `schema`; a zero `eas` disables it
+    /// The schema must be `PHOTO_VERIFICATION_SCHEMA`, registered without a
+    /// resolver. Verifier owner only: a failing EAS contract would block every
+    /// verification
+    pub fn set_eas_config(&mut self, eas: Address, schema: B256) -> Result<(), EasError> {
+        let sender = self.vm().msg_sender();
+        let owner = verifier::contract_owner(self.vm(), self.verifier.get());
+        if owner == Address::ZERO || sender != owner {
+            return Err(EasError::NotOwner(NotOwner { caller: sender }));
+        }
+        if eas != Address::ZERO && (schema == B256::ZERO || self.vm().code_size(eas) == 0) {
+            return Err(EasError::InvalidEasConfig(InvalidEasConfig { eas, schema }));
+        }
+        self.eas.set(eas);
+        self.schema.set(schema);
+
+        emit(self.vm(), EasConfigUpdated { eas, schema, sender });
+        Ok(())
+    }
+
+    /// Get the EAS mirror settings: `(eas, schema)`
+    pub fn get_eas_config(&self) -> Result<(Address, B256), EasError> {
+        Ok((self.eas.get(), self.schema.get()))
+    }
+
+    /// Get the EAS attestation UID of a photo; zero when it isn't mirrored
+    pub fn get_eas_uid(&self, photo_hash: U256) -> Result<B256, EasError> {
+        Ok(self.records.getter(photo_hash).uid.get())
+    }
+
+    /// Get the Verifier whose photos are mirrored
+    pub fn get_verifier(&self) -> Result<Address, EasError> {
+        Ok(self.verifier.get())
+    }
+}
+
+impl Eas {
+    /// Create the EAS attestation of a photo for `photographer` and keep its UID
+    /// `ref_uid` links a re-issued record to the one it replaces
+    fn attest(&mut self, photo_hash: U256, photographer: Address, zk_commitment: U256, ref_uid: B256) -> Result<(), EasError> {
+        let eas = self.eas.get();
+        if eas == Address::ZERO {
+            return Ok(());
+        }
+
+        // The Verifier's companions only pass text CIDs
+        let record = self.records.getter(photo_hash);
+        let ipfs_cid = String::from_utf8(record.cid.get_bytes()).unwrap_or_default();
+        let request = eas::attestation_request(
+            self.schema.get(),
+            photo_hash,
+            record.verified_at.get(),
+            photographer,
+            ipfs_cid,
+            zk_commitment,
+            ref_uid,
+        );
+        let config = Call::new_mutating(self);
+        let uid = eas::IEAS::new(eas)
+            .attest(self.vm(), config, request)
+            .map_err(|_| EasError::EasAttestationFailed(EasAttestationFailed { photo_hash }))?;
+        self.records.setter(photo_hash).uid.set(uid);
+
+        emit(self.vm(), EasAttested { photo_hash, uid });
+        Ok(())
+    }
+
+    /// Revoke EAS attestation `uid` of a photo
+    /// Best effort: a record made under an earlier EAS config can't be revoked
+    /// through the current one, and that mustn't block a takedown or a transfer
+    fn revoke(&mut self, photo_hash: U256, uid: B256) {
+        let eas = self.eas.get();
+        if eas == Address::ZERO {
+            return;
+        }
+
+        let request = eas::revocation_request(self.schema.get(), uid);
+        let config = Call::new_mutating(self);
+        if eas::IEAS::new(eas).revoke(self.vm(), config, request).is_err() {
+            emit(self.vm(), EasRevocationFailed { photo_hash, uid });
+            return;
+        }
+        emit(self.vm(), EasRevoked { photo_hash, uid });
+    }
+}
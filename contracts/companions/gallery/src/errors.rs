@@ -0,0 +1,13 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Typed revert reasons for the Gallery contract.
+
+use stylus_sdk::prelude::*;
+
+pub use arbipic_common::errors::NotVerifier;
+
+#[derive(SolidityError)]
+pub enum GalleryError {
+    NotVerifier(NotVerifier),
+}
@@ -0,0 +1,290 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! On-chain galleries of the Verifier's photos.
+//!
+//! A Verifier hook listing each owner's active photos and every photo in
+//! verification order, behind paginated getters. It only lists photos
+//! verified after it was added with `setHook`.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+
+extern crate alloc;
+
+mod errors;
+
+pub use errors::*;
+use alloc::vec::Vec;
+use arbipic_common::verifier;
+use stylus_sdk::storage::*;
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, U256},
+    prelude::*,
+};
+
+/// Most entries returned by one page of a paginated getter
+pub const MAX_PAGE_SIZE: usize = 100;
+
+/// One `get_photos` entry: `(photo_hash, verified_at, owner, zk_commitment, revoked_at)`
+pub type AttestationEntry = (U256, U256, Address, U256, U256);
+
+// Where a listed photo sits
+#[storage]
+pub struct Listing {
+    id: StorageU256,              // Sequential attestation ID, starting at 1
+    owner_index: StorageU256,     // Position in the owner's photo list
+}
+
+#[storage]
+#[entrypoint]
+pub struct Gallery {
+    // Verifier whose photos are listed
+    verifier: StorageAddress,
+
+    // Active photo hashes of each owner
+    owner_photos: StorageMap<Address, StorageVec<StorageU256>>,
+
+    // Photo hashes in verification order: attestation ID `n` is at index `n - 1`
+    photo_ids: StorageVec<StorageU256>,
+
+    // Listings: photoHash => listing
+    listings: StorageMap<U256, Listing>,
+}
+
+#[public]
+impl Gallery {
+    /// Bind the gallery to the Verifier it lists
+    #[constructor]
+    pub fn constructor(&mut self, verifier: Address) {
+        self.verifier.set(verifier);
+    }
+
+    /// Verifier hook: list a new photo, move a transferred one and drop a revoked one
+    /// Photos verified before the gallery was hooked up are ignored
+    pub fn on_photo_moved(
+        &mut self,
+        photo_hash: U256,
+        from: Address,
+        to: Address,
+        _zk_commitment: U256,
+        _cid: Bytes,
+    ) -> Result<(), GalleryError> {
+        self.only_verifier()?;
+        if from == Address::ZERO {
+            self.photo_ids.push(photo_hash);
+            let id = U256::from(self.photo_ids.len());
+            self.listings.setter(photo_hash).id.set(id);
+        } else if self.listings.getter(photo_hash).id.get() > U256::ZERO {
+            self.unlist_photo(from, photo_hash);
+        } else {
+            return Ok(());
+        }
+        if to != Address::ZERO {
+            self.list_photo(to, photo_hash);
+        }
+        Ok(())
+    }
+
+    /// Page through the active photos of an owner
+    /// Transfers and revocations reorder the list; a short page marks the end
+    pub fn get_owner_photos(&self, owner: Address, offset: U256, limit: U256) -> Result<Vec<U256>, GalleryError> {
+        let photos = self.owner_photos.getter(owner);
+        let (start, end) = page(photos.len(), offset, limit);
+        Ok((start..end).filter_map(|i| photos.get(i)).collect())
+    }
+
+    /// Get the sequential attestation ID of a photo; zero when unlisted
+    pub fn get_photo_id(&self, photo_hash: U256) -> Result<U256, GalleryError> {
+        Ok(self.listings.getter(photo_hash).id.get())
+    }
+
+    /// Get the photo hash behind an attestation ID; zero when unassigned
+    pub fn get_photo_by_id(&self, id: U256) -> Result<U256, GalleryError> {
+        if id == U256::ZERO || id > U256::from(self.photo_ids.len()) {
+            return Ok(U256::ZERO);
+        }
+        Ok(self.photo_ids.get(id - U256::from(1)).unwrap_or_default())
+    }
+
+    /// Walk the listed photos in verification order, starting from ID `offset + 1`
+    pub fn get_photos(&self, offset: U256, limit: U256) -> Result<Vec<AttestationEntry>, GalleryError> {
+        let verifier = self.verifier.get();
+        let (start, end) = page(self.photo_ids.len(), offset, limit);
+        Ok((start..end)
+            .filter_map(|i| self.photo_ids.get(i))
+            .map(|photo_hash| {
+                let attestation = verifier::attestation(self.vm(), verifier, photo_hash);
+                (
+                    photo_hash,
+                    attestation.verified_at,
+                    attestation.owner,
+                    attestation.zk_commitment,
+                    attestation.revoked_at,
+                )
+            })
+            .collect())
+    }
+
+    /// Get the number of listed photos
+    pub fn get_listed_count(&self) -> Result<U256, GalleryError> {
+        Ok(U256::from(self.photo_ids.len()))
+    }
+
+    /// Get the Verifier this gallery lists
+    pub fn get_verifier(&self) -> Result<Address, GalleryError> {
+        Ok(self.verifier.get())
+    }
+}
+
+impl Gallery {
+    /// Append a photo to its owner's list
+    fn list_photo(&mut self, owner: Address, photo_hash: U256) {
+        let mut photos = self.owner_photos.setter(owner);
+        let index = U256::from(photos.len());
+        photos.push(photo_hash);
+        self.listings.setter(photo_hash).owner_index.set(index);
+    }
+
+    /// Remove a photo from its owner's list by swapping in the last entry
+    fn unlist_photo(&mut self, owner: Address, photo_hash: U256) {
+        let index = self.listings.getter(photo_hash).owner_index.get().saturating_to::<usize>();
+        let mut photos = self.owner_photos.setter(owner);
+        let Some(last) = photos.pop() else { return };
+        if last != photo_hash {
+            if let Some(mut slot) = photos.setter(index) {
+                slot.set(last);
+            }
+            self.listings.setter(last).owner_index.set(U256::from(index));
+        }
+    }
+
+    /// Revert unless called by the Verifier
+    fn only_verifier(&self) -> Result<(), GalleryError> {
+        let sender = self.vm().msg_sender();
+        if sender != self.verifier.get() {
+            return Err(GalleryError::NotVerifier(NotVerifier { caller: sender }));
+        }
+        Ok(())
+    }
+}
+
+/// Clamp `offset`/`limit` to a `start..end` range of a list of `len` entries
+fn page(len: usize, offset: U256, limit: U256) -> (usize, usize) {
+    let start = offset.min(U256::from(len)).saturating_to::<usize>();
+    let limit = limit.min(U256::from(MAX_PAGE_SIZE)).saturating_to::<usize>();
+    (start, (start + limit).min(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::testing::*;
+
+    const VERIFIER: Address = Address::repeat_byte(0xee);
+    const ALICE: Address = Address::repeat_byte(2);
+    const BOB: Address = Address::repeat_byte(3);
+
+    /// Gallery of `VERIFIER`, called by it
+    fn deploy() -> (TestVM, Gallery) {
+        let vm = TestVM::default();
+        let mut contract = Gallery::from(&vm);
+        contract.constructor(VERIFIER);
+        vm.set_sender(VERIFIER);
+        (vm, contract)
+    }
+
+    fn hashes(range: core::ops::RangeInclusive<u64>) -> Vec<U256> {
+        range.map(U256::from).collect()
+    }
+
+    fn move_photo(contract: &mut Gallery, photo: u64, from: Address, to: Address) {
+        let moved = contract.on_photo_moved(U256::from(photo), from, to, U256::ZERO, Bytes::default());
+        assert!(moved.is_ok());
+    }
+
+    fn owner_photos(contract: &Gallery, owner: Address) -> Vec<U256> {
+        contract.get_owner_photos(owner, U256::ZERO, U256::MAX).unwrap_or_default()
+    }
+
+    #[test]
+    fn only_the_verifier_calls_the_hook() {
+        let (vm, mut contract) = deploy();
+        vm.set_sender(ALICE);
+
+        let moved = contract.on_photo_moved(U256::from(1), Address::ZERO, ALICE, U256::ZERO, Bytes::default());
+        assert!(matches!(moved, Err(GalleryError::NotVerifier(NotVerifier { caller: ALICE }))));
+        assert_eq!(contract.get_listed_count().ok(), Some(U256::ZERO));
+    }
+
+    #[test]
+    fn mints_get_sequential_ids() {
+        let (_vm, mut contract) = deploy();
+        for photo in 1..=3 {
+            move_photo(&mut contract, photo, Address::ZERO, ALICE);
+        }
+
+        assert_eq!(contract.get_listed_count().ok(), Some(U256::from(3)));
+        assert_eq!(contract.get_photo_id(U256::from(2)).ok(), Some(U256::from(2)));
+        assert_eq!(contract.get_photo_by_id(U256::from(3)).ok(), Some(U256::from(3)));
+        assert_eq!(contract.get_photo_by_id(U256::ZERO).ok(), Some(U256::ZERO));
+        assert_eq!(contract.get_photo_by_id(U256::from(4)).ok(), Some(U256::ZERO));
+        assert_eq!(owner_photos(&contract, ALICE), hashes(1..=3));
+    }
+
+    #[test]
+    fn unlisting_swaps_in_the_last_photo() {
+        let (_vm, mut contract) = deploy();
+        for photo in 1..=4 {
+            move_photo(&mut contract, photo, Address::ZERO, ALICE);
+        }
+
+        move_photo(&mut contract, 2, ALICE, BOB);
+        assert_eq!(owner_photos(&contract, ALICE), [1, 4, 3].map(U256::from));
+        assert_eq!(owner_photos(&contract, BOB), hashes(2..=2));
+
+        // The swapped-in photo's index was updated, so it unlists cleanly too
+        move_photo(&mut contract, 4, ALICE, Address::ZERO);
+        assert_eq!(owner_photos(&contract, ALICE), [1, 3].map(U256::from));
+        move_photo(&mut contract, 3, ALICE, Address::ZERO);
+        assert_eq!(owner_photos(&contract, ALICE), hashes(1..=1));
+
+        // IDs survive transfers and revocations
+        assert_eq!(contract.get_photo_id(U256::from(4)).ok(), Some(U256::from(4)));
+        assert_eq!(contract.get_listed_count().ok(), Some(U256::from(4)));
+    }
+
+    #[test]
+    fn photos_from_before_the_hook_are_ignored() {
+        let (_vm, mut contract) = deploy();
+        move_photo(&mut contract, 1, Address::ZERO, ALICE);
+
+        move_photo(&mut contract, 9, BOB, ALICE);
+        assert_eq!(owner_photos(&contract, ALICE), hashes(1..=1));
+        assert_eq!(contract.get_photo_id(U256::from(9)).ok(), Some(U256::ZERO));
+    }
+
+    #[test]
+    fn owner_pages_are_clamped() {
+        let (_vm, mut contract) = deploy();
+        for photo in 1..=150 {
+            move_photo(&mut contract, photo, Address::ZERO, ALICE);
+        }
+        let page = |offset: u64, limit: U256| contract.get_owner_photos(ALICE, U256::from(offset), limit).unwrap_or_default();
+
+        assert_eq!(page(0, U256::MAX), hashes(1..=MAX_PAGE_SIZE as u64));
+        assert_eq!(page(140, U256::from(20)), hashes(141..=150));
+        assert_eq!(page(10, U256::from(5)), hashes(11..=15));
+        assert!(page(150, U256::from(1)).is_empty());
+        assert!(page(u64::MAX, U256::MAX).is_empty());
+        assert!(page(0, U256::ZERO).is_empty());
+    }
+
+    #[test]
+    fn page_bounds_stay_in_the_list() {
+        assert_eq!(page(10, U256::ZERO, U256::from(5)), (0, 5));
+        assert_eq!(page(10, U256::from(8), U256::from(5)), (8, 10));
+        assert_eq!(page(10, U256::MAX, U256::MAX), (10, 10));
+        assert_eq!(page(500, U256::ZERO, U256::MAX), (0, MAX_PAGE_SIZE));
+    }
+}
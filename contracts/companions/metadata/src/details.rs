@@ -0,0 +1,37 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Optional extended attestation record linking a photo hash to its content.
+
+use alloy_sol_types::sol;
+use stylus_sdk::prelude::*;
+
+/// Longest IPFS CID accepted, in bytes
+pub const MAX_CID_LENGTH: usize = 128;
+/// Longest MIME type accepted, in bytes
+pub const MAX_MIME_TYPE_LENGTH: usize = 64;
+
+sol! {
+    // Set at verification, read back through `get_attestation_details`
+    #[derive(AbiType)]
+    struct AttestationDetails {
+        bytes cid;
+        bytes thumbnailCid;
+        bytes32 locationHash;
+        bytes32 deviceFingerprint;
+        string mimeType;
+    }
+}
+
+/// Check field lengths so a record can't bloat storage, and that CIDs are in
+/// a text form that goes into `ipfs://` URIs, token metadata and EAS records as is
+pub fn is_valid(details: &AttestationDetails) -> bool {
+    is_cid_text(&details.cid)
+        && is_cid_text(&details.thumbnailCid)
+        && details.mimeType.len() <= MAX_MIME_TYPE_LENGTH
+}
+
+/// Empty, or ASCII letters and digits: base58btc (`Qm...`) and base32 (`bafy...`) CIDs
+fn is_cid_text(cid: &[u8]) -> bool {
+    cid.len() <= MAX_CID_LENGTH && cid.iter().all(u8::is_ascii_alphanumeric)
+}
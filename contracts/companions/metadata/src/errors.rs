@@ -0,0 +1,36 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Typed revert reasons for the Metadata contract.
+
+use alloy_sol_types::sol;
+use stylus_sdk::prelude::*;
+
+pub use arbipic_common::errors::{
+    AttestationIsRevoked, EnforcedPause, InvalidCommitment, NotPhotoOwner, PhotoNotVerified, VerifierRejected,
+};
+
+sol! {
+    // Extended record has an oversized or non-text CID or MIME type
+    error InvalidDetails(uint256 photo_hash);
+    // Attribute key or value is empty or too long
+    error InvalidAttribute(uint256 photo_hash, string key);
+    // Attributes are append-only; this key is already set
+    error AttributeAlreadySet(uint256 photo_hash, string key);
+    // Photo already has the maximum number of attributes
+    error TooManyAttributes(uint256 photo_hash);
+}
+
+#[derive(SolidityError)]
+pub enum MetadataError {
+    InvalidCommitment(InvalidCommitment),
+    InvalidDetails(InvalidDetails),
+    PhotoNotVerified(PhotoNotVerified),
+    NotPhotoOwner(NotPhotoOwner),
+    AttestationIsRevoked(AttestationIsRevoked),
+    EnforcedPause(EnforcedPause),
+    InvalidAttribute(InvalidAttribute),
+    AttributeAlreadySet(AttributeAlreadySet),
+    TooManyAttributes(TooManyAttributes),
+    VerifierRejected(VerifierRejected),
+}
@@ -0,0 +1,13 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Logs emitted by the Metadata contract.
+
+use alloy_sol_types::sol;
+
+sol! {
+    // A photo's extended record was stored
+    event AttestationDetailsSet(uint256 indexed photo_hash, bytes cid);
+    // An extension attribute was added to a photo
+    event AttributeSet(uint256 indexed photo_hash, string key, bytes value);
+}
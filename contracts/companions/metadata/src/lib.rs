@@ -0,0 +1,181 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Content metadata for the Verifier's photos: an optional extended record
+//! set at verification, and append-only key-value attributes.
+//!
+//! Photos with a record are verified here and recorded through the Verifier's
+//! `recordPhoto`, which needs `COMPANION_ROLE`; their CID goes with them, so
+//! hooks such as the token and EAS companions can use it.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+
+extern crate alloc;
+
+mod details;
+mod errors;
+mod events;
+
+pub use details::AttestationDetails;
+pub use errors::*;
+pub use events::*;
+use alloc::{string::String, vec::Vec};
+use arbipic_common::{
+    log::emit,
+    verifier::{self, IVerifier},
+};
+use stylus_sdk::storage::*;
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, U256},
+    prelude::*,
+};
+
+// Extended record linking a photo hash to its content
+#[storage]
+pub struct PhotoDetails {
+    cid: StorageBytes,            // IPFS CID of the full image
+    thumbnail_cid: StorageBytes,  // IPFS CID of the thumbnail
+    location_hash: StorageB256,   // Salted hash of the capture location
+    device_fingerprint: StorageB256, // Hash of the capturing device's fingerprint
+    mime_type: StorageString,     // e.g. "image/jpeg"
+}
+
+// Owner-added extension attributes of one photo
+#[storage]
+pub struct PhotoAttributes {
+    keys: StorageVec<StorageString>, // Keys in the order they were added
+    values: StorageMap<String, StorageBytes>, // Key => value; empty while unset
+}
+
+/// Most extension attributes per photo
+pub const MAX_ATTRIBUTES: usize = 32;
+/// Longest attribute key, in bytes
+pub const MAX_ATTRIBUTE_KEY_LENGTH: usize = 64;
+/// Longest attribute value, in bytes
+pub const MAX_ATTRIBUTE_VALUE_LENGTH: usize = 1024;
+
+#[storage]
+#[entrypoint]
+pub struct Metadata {
+    // Verifier the photos are recorded on
+    verifier: StorageAddress,
+
+    // Optional extended records, keyed by photo hash
+    details: StorageMap<U256, PhotoDetails>,
+
+    // Append-only key => bytes extension attributes, keyed by photo hash
+    attributes: StorageMap<U256, PhotoAttributes>,
+}
+
+#[public]
+impl Metadata {
+    /// Bind the contract to the Verifier it records photos on
+    #[constructor]
+    pub fn constructor(&mut self, verifier: Address) {
+        self.verifier.set(verifier);
+    }
+
+    /// Verify a photo for the caller and record where its content lives
+    /// Every field of `details` is optional; CIDs are in their text form (`bafy...`, `Qm...`)
+    pub fn verify_photo_with_details(&mut self, photo_hash: U256, zk_commitment: U256, details: AttestationDetails) -> Result<U256, MetadataError> {
+        if zk_commitment == U256::ZERO {
+            return Err(MetadataError::InvalidCommitment(InvalidCommitment { photo_hash }));
+        }
+        if !details::is_valid(&details) {
+            return Err(MetadataError::InvalidDetails(InvalidDetails { photo_hash }));
+        }
+
+        let sender = self.vm().msg_sender();
+        let verifier = self.verifier.get();
+        let config = Call::new_mutating(self);
+        let timestamp = IVerifier::new(verifier)
+            .record_photo(self.vm(), config, sender, photo_hash, zk_commitment, details.cid.clone())
+            .map_err(|error| MetadataError::VerifierRejected(verifier::rejected(error)))?;
+
+        let mut record = self.details.setter(photo_hash);
+        record.cid.set_bytes(&details.cid);
+        record.thumbnail_cid.set_bytes(&details.thumbnailCid);
+        record.location_hash.set(details.locationHash);
+        record.device_fingerprint.set(details.deviceFingerprint);
+        record.mime_type.set_str(&details.mimeType);
+
+        emit(self.vm(), AttestationDetailsSet { photo_hash, cid: details.cid });
+        Ok(timestamp)
+    }
+
+    /// Get the extended record of a photo; empty when none was given
+    pub fn get_attestation_details(&self, photo_hash: U256) -> Result<AttestationDetails, MetadataError> {
+        let record = self.details.getter(photo_hash);
+        Ok(AttestationDetails {
+            cid: record.cid.get_bytes().into(),
+            thumbnailCid: record.thumbnail_cid.get_bytes().into(),
+            locationHash: record.location_hash.get(),
+            deviceFingerprint: record.device_fingerprint.get(),
+            mimeType: record.mime_type.get_string(),
+        })
+    }
+
+    /// Attach an extension attribute (camera model, case number, ...) to a photo
+    /// the caller owns; attributes are append-only, so a key is written once
+    pub fn set_attribute(&mut self, photo_hash: U256, key: String, value: Bytes) -> Result<(), MetadataError> {
+        self.only_photo_owner(photo_hash)?;
+        if key.is_empty() || key.len() > MAX_ATTRIBUTE_KEY_LENGTH || value.is_empty() || value.len() > MAX_ATTRIBUTE_VALUE_LENGTH {
+            return Err(MetadataError::InvalidAttribute(InvalidAttribute { photo_hash, key }));
+        }
+
+        let mut attributes = self.attributes.setter(photo_hash);
+        if !attributes.values.getter(key.clone()).is_empty() {
+            return Err(MetadataError::AttributeAlreadySet(AttributeAlreadySet { photo_hash, key }));
+        }
+        if attributes.keys.len() >= MAX_ATTRIBUTES {
+            return Err(MetadataError::TooManyAttributes(TooManyAttributes { photo_hash }));
+        }
+        attributes.keys.grow().set_str(&key);
+        attributes.values.setter(key.clone()).set_bytes(&value);
+
+        emit(self.vm(), AttributeSet { photo_hash, key, value });
+        Ok(())
+    }
+
+    /// Get an extension attribute; empty when the key is unset
+    pub fn get_attribute(&self, photo_hash: U256, key: String) -> Result<Bytes, MetadataError> {
+        Ok(self.attributes.getter(photo_hash).values.getter(key).get_bytes().into())
+    }
+
+    /// List the attribute keys of a photo in the order they were added
+    pub fn get_attribute_keys(&self, photo_hash: U256) -> Result<Vec<String>, MetadataError> {
+        let attributes = self.attributes.getter(photo_hash);
+        Ok((0..attributes.keys.len())
+            .filter_map(|i| attributes.keys.getter(i))
+            .map(|key| key.get_string())
+            .collect())
+    }
+
+    /// Get the Verifier photos are recorded on
+    pub fn get_verifier(&self) -> Result<Address, MetadataError> {
+        Ok(self.verifier.get())
+    }
+}
+
+impl Metadata {
+    /// Revert while the Verifier is paused, or unless the caller owns the
+    /// active attestation there
+    fn only_photo_owner(&self, photo_hash: U256) -> Result<(), MetadataError> {
+        let verifier = self.verifier.get();
+        if verifier::is_paused(self.vm(), verifier) {
+            return Err(MetadataError::EnforcedPause(EnforcedPause {}));
+        }
+        let attestation = verifier::attestation(self.vm(), verifier, photo_hash);
+        if attestation.verified_at == U256::ZERO {
+            return Err(MetadataError::PhotoNotVerified(PhotoNotVerified { photo_hash }));
+        }
+        if attestation.revoked_at > U256::ZERO {
+            return Err(MetadataError::AttestationIsRevoked(AttestationIsRevoked { photo_hash }));
+        }
+        if attestation.owner != self.vm().msg_sender() {
+            return Err(MetadataError::NotPhotoOwner(NotPhotoOwner { photo_hash, owner: attestation.owner }));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Typed revert reasons for the PHash contract.
+
+use alloy_sol_types::sol;
+use stylus_sdk::prelude::*;
+
+pub use arbipic_common::errors::{
+    AttestationIsRevoked, EnforcedPause, InvalidCommitment, NotPhotoOwner, PhotoNotVerified, VerifierRejected,
+};
+
+sol! {
+    // Photo already has a perceptual hash
+    error PerceptualHashAlreadySet(uint256 photo_hash);
+    // Near-duplicate search radius is above the supported maximum
+    error InvalidHammingDistance(uint8 max_distance);
+    // Thumbnail is not a 32x32 grayscale image
+    error InvalidThumbnail(uint256 length);
+}
+
+#[derive(SolidityError)]
+pub enum PHashError {
+    InvalidCommitment(InvalidCommitment),
+    PhotoNotVerified(PhotoNotVerified),
+    NotPhotoOwner(NotPhotoOwner),
+    AttestationIsRevoked(AttestationIsRevoked),
+    EnforcedPause(EnforcedPause),
+    PerceptualHashAlreadySet(PerceptualHashAlreadySet),
+    InvalidHammingDistance(InvalidHammingDistance),
+    InvalidThumbnail(InvalidThumbnail),
+    VerifierRejected(VerifierRejected),
+}
@@ -0,0 +1,11 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Logs emitted by the PHash contract.
+
+use alloy_sol_types::sol;
+
+sol! {
+    // A photo's perceptual hash was recorded; `computed` if derived on-chain from a thumbnail
+    event PerceptualHashSet(uint256 indexed photo_hash, uint64 perceptual_hash, bool computed);
+}
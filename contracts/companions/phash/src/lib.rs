@@ -0,0 +1,204 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Perceptual hashes of the Verifier's photos, and a near-duplicate index
+//! that traces re-encoded or resized copies back to the original.
+//!
+//! Photos verified here are recorded through the Verifier's `recordPhoto`,
+//! which needs `COMPANION_ROLE`; owners of photos verified elsewhere add their
+//! pHash with `set_perceptual_hash`.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+
+extern crate alloc;
+
+mod errors;
+mod events;
+mod phash;
+
+pub use errors::*;
+pub use events::*;
+use alloc::vec::Vec;
+use arbipic_common::{
+    log::emit,
+    verifier::{self, IVerifier},
+};
+use stylus_sdk::storage::*;
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, U256, U64},
+    prelude::*,
+};
+
+/// Most photo hashes returned by one `find_similar_photos` call
+pub const MAX_SIMILAR_RESULTS: usize = 100;
+/// Most index entries one `find_similar_photos` call reads
+pub const MAX_SCAN: usize = 1024;
+
+// Perceptual hash of one photo
+#[storage]
+pub struct PerceptualHash {
+    value: StorageU64,            // 64-bit pHash of the image content
+    is_set: StorageBool,
+    computed: StorageBool,        // Derived here from a thumbnail, not supplied by the client
+}
+
+#[storage]
+#[entrypoint]
+pub struct PHash {
+    // Verifier the photos are recorded on
+    verifier: StorageAddress,
+
+    // Perceptual hashes: photoHash => pHash
+    hashes: StorageMap<U256, PerceptualHash>,
+
+    // Near-duplicate index: `phash::bucket_key` => photo hashes
+    buckets: StorageMap<U256, StorageVec<StorageU256>>,
+}
+
+#[public]
+impl PHash {
+    /// Bind the contract to the Verifier it records photos on
+    #[constructor]
+    pub fn constructor(&mut self, verifier: Address) {
+        self.verifier.set(verifier);
+    }
+
+    /// Verify a photo together with its 64-bit perceptual hash
+    /// The pHash survives re-encoding and resizing, so copies can be traced back
+    pub fn verify_photo_with_phash(&mut self, photo_hash: U256, zk_commitment: U256, perceptual_hash: u64) -> Result<U256, PHashError> {
+        self.verify_with_perceptual_hash(photo_hash, zk_commitment, perceptual_hash, false)
+    }
+
+    /// Verify a photo and compute its perceptual hash on-chain
+    /// `thumbnail` is a row-major 32x32 grayscale rendering of the photo, one
+    /// byte per pixel; the pHash is derived here instead of trusting the client
+    pub fn verify_photo_with_thumbnail(&mut self, photo_hash: U256, zk_commitment: U256, thumbnail: Bytes) -> Result<U256, PHashError> {
+        let Some(perceptual_hash) = phash::compute(&thumbnail) else {
+            return Err(PHashError::InvalidThumbnail(InvalidThumbnail { length: U256::from(thumbnail.len()) }));
+        };
+        self.verify_with_perceptual_hash(photo_hash, zk_commitment, perceptual_hash, true)
+    }
+
+    /// Add a perceptual hash to a photo the caller owns on the Verifier; set once
+    pub fn set_perceptual_hash(&mut self, photo_hash: U256, perceptual_hash: u64) -> Result<(), PHashError> {
+        self.only_photo_owner(photo_hash)?;
+        if self.hashes.getter(photo_hash).is_set.get() {
+            return Err(PHashError::PerceptualHashAlreadySet(PerceptualHashAlreadySet { photo_hash }));
+        }
+
+        self.store_perceptual_hash(photo_hash, perceptual_hash, false);
+        Ok(())
+    }
+
+    /// Get the perceptual hash of a photo: `(is_set, perceptual_hash, computed)`
+    /// `computed` is true when the pHash was derived on-chain from a thumbnail
+    pub fn get_perceptual_hash(&self, photo_hash: U256) -> Result<(bool, u64, bool), PHashError> {
+        let entry = self.hashes.getter(photo_hash);
+        Ok((entry.is_set.get(), entry.value.get().saturating_to::<u64>(), entry.computed.get()))
+    }
+
+    /// Find active photos whose perceptual hash is within `max_distance` bits:
+    /// `(photo_hashes, next_cursor)`
+    /// Reads at most `MAX_SCAN` index entries and returns at most
+    /// `MAX_SIMILAR_RESULTS`, so a flooded bucket can't push the call out of gas.
+    /// Start from cursor zero and call again with `next_cursor` until it is zero.
+    /// With `computed_only`, client-supplied pHashes are skipped
+    pub fn find_similar_photos(
+        &self,
+        perceptual_hash: u64,
+        max_distance: u8,
+        computed_only: bool,
+        cursor: u64,
+    ) -> Result<(Vec<U256>, u64), PHashError> {
+        if max_distance > phash::MAX_DISTANCE {
+            return Err(PHashError::InvalidHammingDistance(InvalidHammingDistance { max_distance }));
+        }
+
+        let verifier = self.verifier.get();
+        let mut matches = Vec::new();
+        let mut scanned = 0;
+        let (first, mut start) = phash::split_cursor(cursor);
+        for index in first..phash::PROBES {
+            let band = phash::probe_band(index);
+            let bucket = self.buckets.getter(phash::probe(perceptual_hash, index));
+            for i in start..bucket.len() {
+                if scanned == MAX_SCAN || matches.len() == MAX_SIMILAR_RESULTS {
+                    return Ok((matches, phash::cursor(index, i)));
+                }
+                scanned += 1;
+                let Some(photo_hash) = bucket.get(i) else { continue };
+                let entry = self.hashes.getter(photo_hash);
+                let candidate = entry.value.get().saturating_to::<u64>();
+                // A photo is filed once per band; report it from the first band probed
+                if phash::first_probed_band(perceptual_hash, candidate) != Some(band)
+                    || (computed_only && !entry.computed.get())
+                    || phash::distance(candidate, perceptual_hash) > max_distance
+                    || !verifier::is_verified(self.vm(), verifier, photo_hash)
+                {
+                    continue;
+                }
+                matches.push(photo_hash);
+            }
+            start = 0;
+        }
+        Ok((matches, 0))
+    }
+
+    /// Get the Verifier photos are recorded on
+    pub fn get_verifier(&self) -> Result<Address, PHashError> {
+        Ok(self.verifier.get())
+    }
+}
+
+impl PHash {
+    /// Record a new photo on the Verifier for the caller, with its pHash
+    fn verify_with_perceptual_hash(&mut self, photo_hash: U256, zk_commitment: U256, perceptual_hash: u64, computed: bool) -> Result<U256, PHashError> {
+        if zk_commitment == U256::ZERO {
+            return Err(PHashError::InvalidCommitment(InvalidCommitment { photo_hash }));
+        }
+
+        let sender = self.vm().msg_sender();
+        let verifier = self.verifier.get();
+        let config = Call::new_mutating(self);
+        let timestamp = IVerifier::new(verifier)
+            .record_photo(self.vm(), config, sender, photo_hash, zk_commitment, Bytes::default())
+            .map_err(|error| PHashError::VerifierRejected(verifier::rejected(error)))?;
+        self.store_perceptual_hash(photo_hash, perceptual_hash, computed);
+        Ok(timestamp)
+    }
+
+    /// Record a perceptual hash and file the photo under each band's bucket
+    fn store_perceptual_hash(&mut self, photo_hash: U256, perceptual_hash: u64, computed: bool) {
+        let mut entry = self.hashes.setter(photo_hash);
+        entry.value.set(U64::from(perceptual_hash));
+        entry.is_set.set(true);
+        entry.computed.set(computed);
+        for band in 0..phash::BANDS {
+            let key = phash::bucket_key(band, phash::chunk(perceptual_hash, band));
+            self.buckets.setter(key).push(photo_hash);
+        }
+
+        emit(self.vm(), PerceptualHashSet { photo_hash, perceptual_hash, computed });
+    }
+
+    /// Revert while the Verifier is paused, or unless the caller owns the
+    /// active attestation there
+    fn only_photo_owner(&self, photo_hash: U256) -> Result<(), PHashError> {
+        let verifier = self.verifier.get();
+        if verifier::is_paused(self.vm(), verifier) {
+            return Err(PHashError::EnforcedPause(EnforcedPause {}));
+        }
+        let attestation = verifier::attestation(self.vm(), verifier, photo_hash);
+        if attestation.verified_at == U256::ZERO {
+            return Err(PHashError::PhotoNotVerified(PhotoNotVerified { photo_hash }));
+        }
+        if attestation.revoked_at > U256::ZERO {
+            return Err(PHashError::AttestationIsRevoked(AttestationIsRevoked { photo_hash }));
+        }
+        if attestation.owner != self.vm().msg_sender() {
+            return Err(PHashError::NotPhotoOwner(NotPhotoOwner { photo_hash, owner: attestation.owner }));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! 64-bit perceptual hashes and their near-duplicate index.
+//!
+//! `compute` derives a DCT pHash from a 32x32 grayscale thumbnail in fixed
+//! point, bit for bit the same as `perceptualHashFromThumbnail` in the frontend.
+//!
+//! A hash is split into four 16-bit bands and each attestation is filed under
+//! `(band, chunk)` for every band. Two hashes within Hamming distance 7 differ
+//! in at most one bit in at least one band, so probing each band's chunk and
+//! its 16 one-bit neighbours finds every candidate. Lookups walk the probed
+//! buckets in order, a bounded number of entries per call, and resume from a
+//! cursor; a photo reachable through several bands is reported from the first.
+
+use alloc::vec::Vec;
+use stylus_sdk::alloy_primitives::U256;
+
+/// Side of the square grayscale thumbnail hashed by `compute`
+pub const THUMBNAIL_SIZE: usize = 32;
+
+/// Side of the low-frequency DCT block that becomes the hash
+const BLOCK: usize = 8;
+
+/// `round(4096 * cos(k * PI / 64))` for `k` in `0..=32`
+const COS_TABLE: [i64; 33] = [
+    4096, 4091, 4076, 4052, 4017, 3973, 3920, 3857, 3784, 3703, 3612, 3513, 3406, 3290, 3166, 3035,
+    2896, 2751, 2598, 2440, 2276, 2106, 1931, 1751, 1567, 1380, 1189, 995, 799, 601, 401, 201, 0,
+];
+
+/// Number of 16-bit bands a hash is indexed under
+pub const BANDS: usize = 4;
+
+/// Largest Hamming distance `find_similar_photos` can search exhaustively
+pub const MAX_DISTANCE: u8 = 7;
+
+/// Buckets probed per band: the chunk and its 16 one-bit neighbours
+const PROBES_PER_BAND: usize = 17;
+
+/// Buckets probed for one lookup, band by band
+pub const PROBES: usize = BANDS * PROBES_PER_BAND;
+
+/// Bucket key of a band chunk: `band << 16 | chunk`
+pub fn bucket_key(band: usize, chunk: u16) -> U256 {
+    U256::from((band as u64) << 16 | chunk as u64)
+}
+
+/// Chunk of `hash` in `band`, most significant band first
+pub fn chunk(hash: u64, band: usize) -> u16 {
+    (hash >> (16 * (BANDS - 1 - band))) as u16
+}
+
+/// Band searched by probe `index`
+pub fn probe_band(index: usize) -> usize {
+    index / PROBES_PER_BAND
+}
+
+/// Bucket of probe `index` for `hash`: each band's chunk, then its one-bit neighbours
+pub fn probe(hash: u64, index: usize) -> U256 {
+    let band = probe_band(index);
+    let chunk = match index % PROBES_PER_BAND {
+        0 => chunk(hash, band),
+        bit => chunk(hash, band) ^ (1 << (bit - 1)),
+    };
+    bucket_key(band, chunk)
+}
+
+/// First band whose probes for `hash` reach a bucket `other` is filed under
+pub fn first_probed_band(hash: u64, other: u64) -> Option<usize> {
+    (0..BANDS).find(|&band| (chunk(hash, band) ^ chunk(other, band)).count_ones() <= 1)
+}
+
+/// Lookup cursor at entry `entry` of probe `index`
+pub fn cursor(index: usize, entry: usize) -> u64 {
+    (index as u64) << 32 | entry as u64
+}
+
+/// `(probe index, entry)` of a lookup cursor
+pub fn split_cursor(cursor: u64) -> (usize, usize) {
+    ((cursor >> 32) as usize, cursor as u32 as usize)
+}
+
+/// pHash of a row-major 32x32 grayscale thumbnail, one byte per pixel
+/// Bit 63 is the DC coefficient; each bit is set when its coefficient in the
+/// 8x8 low-frequency block is above the block's median
+pub fn compute(thumbnail: &[u8]) -> Option<u64> {
+    if thumbnail.len() != THUMBNAIL_SIZE * THUMBNAIL_SIZE {
+        return None;
+    }
+
+    let mut basis = [[0i64; THUMBNAIL_SIZE]; BLOCK];
+    for (u, row) in basis.iter_mut().enumerate() {
+        for (x, c) in row.iter_mut().enumerate() {
+            *c = cosine((2 * x + 1) * u);
+        }
+    }
+
+    // Rows first, then columns
+    let mut rows = [[0i64; BLOCK]; THUMBNAIL_SIZE];
+    for (y, pixels) in thumbnail.chunks_exact(THUMBNAIL_SIZE).enumerate() {
+        for (u, row) in basis.iter().enumerate() {
+            rows[y][u] = row.iter().zip(pixels).map(|(c, p)| c * *p as i64).sum();
+        }
+    }
+    let mut coefficients = Vec::with_capacity(BLOCK * BLOCK);
+    for column in &basis {
+        for u in 0..BLOCK {
+            coefficients.push(column.iter().zip(&rows).map(|(c, row)| c * row[u]).sum::<i64>());
+        }
+    }
+
+    let mut sorted = coefficients.clone();
+    sorted.sort_unstable();
+    let median_times_two = sorted[31] + sorted[32];
+    Some(coefficients.iter().fold(0u64, |hash, value| hash << 1 | (2 * value > median_times_two) as u64))
+}
+
+/// `round(4096 * cos(k * PI / 64))` for any `k`, from the quarter-wave table
+fn cosine(k: usize) -> i64 {
+    let k = k % 128;
+    let k = if k > 64 { 128 - k } else { k };
+    if k <= 32 {
+        COS_TABLE[k]
+    } else {
+        -COS_TABLE[64 - k]
+    }
+}
+
+/// Number of differing bits
+pub fn distance(a: u64, b: u64) -> u8 {
+    (a ^ b).count_ones() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Thumbnail with `pixel(x, y)` at row `y`, column `x`
+    fn thumbnail(pixel: impl Fn(usize, usize) -> u8) -> Vec<u8> {
+        (0..THUMBNAIL_SIZE * THUMBNAIL_SIZE)
+            .map(|i| pixel(i % THUMBNAIL_SIZE, i / THUMBNAIL_SIZE))
+            .collect()
+    }
+
+    /// ZX81 generator `s = (75 * s + 74) % 65537`, low byte per pixel
+    fn noise() -> Vec<u8> {
+        let mut s = 1u32;
+        (0..THUMBNAIL_SIZE * THUMBNAIL_SIZE)
+            .map(|_| {
+                s = (75 * s + 74) % 65537;
+                s as u8
+            })
+            .collect()
+    }
+
+    // Same fixtures hashed by `perceptualHashFromThumbnail` in
+    // frontend/src/utils/perceptualHash.ts; update both sides together
+    #[test]
+    fn compute_matches_frontend() {
+        let horizontal = thumbnail(|x, _| (x * 8) as u8);
+        let blob = thumbnail(|x, y| {
+            let d = (x as i64 - 12).pow(2) + (y as i64 - 20).pow(2);
+            (d / 2).min(255) as u8
+        });
+        let pattern = thumbnail(|x, y| (((x ^ y) * 37 + x * y) & 0xff) as u8);
+
+        assert_eq!(compute(&horizontal), Some(0x8000000000000000));
+        assert_eq!(compute(&blob), Some(0xaad5d5d5d4d0d0d0));
+        assert_eq!(compute(&pattern), Some(0xad57a958b4c843e3));
+        assert_eq!(compute(&noise()), Some(0xc6fab414d4b4d86c));
+    }
+
+    #[test]
+    fn compute_rejects_other_sizes() {
+        assert_eq!(compute(&[0; THUMBNAIL_SIZE * THUMBNAIL_SIZE - 1]), None);
+        assert_eq!(compute(&[0; THUMBNAIL_SIZE * THUMBNAIL_SIZE + 1]), None);
+    }
+
+    #[test]
+    fn cosine_covers_the_full_period() {
+        assert_eq!(cosine(0), 4096);
+        assert_eq!(cosine(32), 0);
+        assert_eq!(cosine(64), -4096);
+        assert_eq!(cosine(96), 0);
+        assert_eq!(cosine(128), 4096);
+        assert_eq!(cosine(10), -cosine(54));
+        assert_eq!(cosine(10), cosine(118));
+    }
+
+    /// Bands whose probes for `hash` reach a bucket `other` is filed under
+    fn probed_bands(hash: u64, other: u64) -> Vec<usize> {
+        (0..PROBES)
+            .filter(|&index| probe(hash, index) == bucket_key(probe_band(index), chunk(other, probe_band(index))))
+            .map(probe_band)
+            .collect()
+    }
+
+    /// Whether probing `hash` reaches a bucket that `other` is filed under
+    fn found(hash: u64, other: u64) -> bool {
+        !probed_bands(hash, other).is_empty()
+    }
+
+    #[test]
+    fn probes_find_every_hash_within_max_distance() {
+        let mut state = 0x9e3779b97f4a7c15u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for _ in 0..2000 {
+            let hash = next();
+            let mut other = hash;
+            while distance(hash, other) < MAX_DISTANCE {
+                other ^= 1 << (next() % 64);
+            }
+            for flipped in [hash, other] {
+                assert!(distance(hash, flipped) <= MAX_DISTANCE);
+                assert!(found(hash, flipped), "{hash:#x} misses {flipped:#x}");
+            }
+        }
+    }
+
+    #[test]
+    fn first_probed_band_is_the_first_probe_hit() {
+        let hash = 0x0123456789abcdefu64;
+        for other in [hash, hash ^ 1, hash ^ 0x0001_0000_0000_0000, hash ^ 0x0003_0001_0000_0000, hash ^ 0x0003_0003_0003_0003] {
+            assert_eq!(first_probed_band(hash, other), probed_bands(hash, other).first().copied());
+        }
+    }
+
+    #[test]
+    fn cursor_round_trips() {
+        assert_eq!(split_cursor(0), (0, 0));
+        assert_eq!(split_cursor(cursor(PROBES - 1, 12345)), (PROBES - 1, 12345));
+    }
+
+    #[test]
+    fn probes_can_miss_beyond_max_distance() {
+        // Two bits in every band: distance 8 and no band within one bit
+        let hash = 0x0123456789abcdefu64;
+        let other = hash ^ 0x0003_0003_0003_0003;
+        assert_eq!(distance(hash, other), MAX_DISTANCE + 1);
+        assert!(!found(hash, other));
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Typed revert reasons for the Proofs contract.
+
+use alloy_sol_types::sol;
+use stylus_sdk::prelude::*;
+
+pub use arbipic_common::errors::{
+    AttestationIsRevoked, EnforcedPause, NotPhotoOwner, NotVerifier, PhotoNotVerified, VerifierRejected,
+};
+
+sol! {
+    // Signature proof key is the zero address
+    error InvalidProofKey(uint256 photo_hash);
+}
+
+#[derive(SolidityError)]
+pub enum ProofsError {
+    PhotoNotVerified(PhotoNotVerified),
+    NotPhotoOwner(NotPhotoOwner),
+    AttestationIsRevoked(AttestationIsRevoked),
+    InvalidProofKey(InvalidProofKey),
+    EnforcedPause(EnforcedPause),
+    NotVerifier(NotVerifier),
+    VerifierRejected(VerifierRejected),
+}
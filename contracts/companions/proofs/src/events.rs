@@ -0,0 +1,11 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Logs emitted by the Proofs contract.
+
+use alloy_sol_types::sol;
+
+sol! {
+    // The owner set or rotated the signature proof key of a photo
+    event ProofKeyUpdated(uint256 indexed photo_hash, address indexed owner, address indexed proof_key);
+}
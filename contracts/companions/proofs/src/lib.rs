@@ -0,0 +1,156 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Signature-based ownership proofs: a photo's proof key answers fresh
+//! challenges instead of the owner revealing a secret.
+//!
+//! Photos proved by a key are recorded through the Verifier's `recordPhoto`,
+//! which needs `COMPANION_ROLE`. As a Verifier hook, the contract drops a
+//! photo's proof key once it changes hands or is revoked.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+
+extern crate alloc;
+
+mod errors;
+mod events;
+
+pub use errors::*;
+pub use events::*;
+use arbipic_common::{
+    log::emit,
+    signature,
+    verifier::{self, IVerifier},
+};
+use stylus_sdk::storage::*;
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, B256, U256},
+    prelude::*,
+};
+
+#[storage]
+#[entrypoint]
+pub struct Proofs {
+    // Verifier whose photos are proved
+    verifier: StorageAddress,
+
+    // Signer keys for signature-based ownership proofs: photoHash => key
+    proof_keys: StorageMap<U256, StorageAddress>,
+}
+
+#[public]
+impl Proofs {
+    /// Bind the contract to the Verifier whose photos it proves
+    #[constructor]
+    pub fn constructor(&mut self, verifier: Address) {
+        self.verifier.set(verifier);
+    }
+
+    /// Verifier hook: clear the proof key of a photo that changed hands or was revoked
+    pub fn on_photo_moved(
+        &mut self,
+        photo_hash: U256,
+        from: Address,
+        _to: Address,
+        _zk_commitment: U256,
+        _cid: Bytes,
+    ) -> Result<(), ProofsError> {
+        let sender = self.vm().msg_sender();
+        if sender != self.verifier.get() {
+            return Err(ProofsError::NotVerifier(NotVerifier { caller: sender }));
+        }
+        if from != Address::ZERO {
+            self.proof_keys.setter(photo_hash).set(Address::ZERO);
+        }
+        Ok(())
+    }
+
+    /// Verify a photo whose ownership is proved by signatures instead of a secret
+    /// The attestation commits to `proof_key`, which answers challenges off-chain
+    pub fn verify_photo_with_key(&mut self, photo_hash: U256, proof_key: Address) -> Result<U256, ProofsError> {
+        if proof_key == Address::ZERO {
+            return Err(ProofsError::InvalidProofKey(InvalidProofKey { photo_hash }));
+        }
+
+        let sender = self.vm().msg_sender();
+        let verifier = self.verifier.get();
+        let config = Call::new_mutating(self);
+        let timestamp = IVerifier::new(verifier)
+            .record_photo(self.vm(), config, sender, photo_hash, U256::ZERO, Bytes::default())
+            .map_err(|error| ProofsError::VerifierRejected(verifier::rejected(error)))?;
+        self.proof_keys.setter(photo_hash).set(proof_key);
+
+        emit(self.vm(), ProofKeyUpdated { photo_hash, owner: sender, proof_key });
+        Ok(timestamp)
+    }
+
+    /// Set or rotate the signature proof key of a photo the caller owns
+    pub fn set_proof_key(&mut self, photo_hash: U256, proof_key: Address) -> Result<(), ProofsError> {
+        if verifier::is_paused(self.vm(), self.verifier.get()) {
+            return Err(ProofsError::EnforcedPause(EnforcedPause {}));
+        }
+        if proof_key == Address::ZERO {
+            return Err(ProofsError::InvalidProofKey(InvalidProofKey { photo_hash }));
+        }
+
+        let owner = self.only_photo_owner(photo_hash)?;
+        self.proof_keys.setter(photo_hash).set(proof_key);
+
+        emit(self.vm(), ProofKeyUpdated { photo_hash, owner, proof_key });
+        Ok(())
+    }
+
+    /// Get the signature proof key of a photo
+    pub fn get_proof_key(&self, photo_hash: U256) -> Result<Address, ProofsError> {
+        Ok(self.proof_keys.get(photo_hash))
+    }
+
+    /// Verify a signature-based proof of ownership
+    /// The verifier picks a fresh `challenge`; the owner's proof key signs
+    /// `ownership_challenge_digest` with `personal_sign`, so the answer is
+    /// useless for any other challenge and the key never leaves the owner.
+    /// A contract proof key (e.g. a Safe) is checked through ERC-1271
+    pub fn verify_signature_proof(&self, photo_hash: U256, challenge: B256, signature: Bytes) -> Result<bool, ProofsError> {
+        let proof_key = self.proof_keys.get(photo_hash);
+        if proof_key == Address::ZERO || !verifier::is_verified(self.vm(), self.verifier.get(), photo_hash) {
+            return Ok(false);
+        }
+
+        let digest = self.ownership_challenge_digest(photo_hash, challenge)?;
+        let digest = signature::eth_signed_message_hash(digest);
+        Ok(signature::is_valid_signature_now(self.vm(), proof_key, digest, &signature))
+    }
+
+    /// Digest the proof key must sign to answer `challenge` for `photo_hash`
+    pub fn ownership_challenge_digest(&self, photo_hash: U256, challenge: B256) -> Result<B256, ProofsError> {
+        Ok(signature::ownership_challenge_digest(
+            self.vm().chain_id(),
+            self.vm().contract_address(),
+            photo_hash,
+            challenge,
+        ))
+    }
+
+    /// Get the Verifier whose photos are proved
+    pub fn get_verifier(&self) -> Result<Address, ProofsError> {
+        Ok(self.verifier.get())
+    }
+}
+
+impl Proofs {
+    /// Revert unless the caller owns the active attestation on the Verifier; returns the owner
+    fn only_photo_owner(&self, photo_hash: U256) -> Result<Address, ProofsError> {
+        let attestation = verifier::attestation(self.vm(), self.verifier.get(), photo_hash);
+        if attestation.verified_at == U256::ZERO {
+            return Err(ProofsError::PhotoNotVerified(PhotoNotVerified { photo_hash }));
+        }
+        if attestation.revoked_at > U256::ZERO {
+            return Err(ProofsError::AttestationIsRevoked(AttestationIsRevoked { photo_hash }));
+        }
+        if attestation.owner != self.vm().msg_sender() {
+            return Err(ProofsError::NotPhotoOwner(NotPhotoOwner { photo_hash, owner: attestation.owner }));
+        }
+        Ok(attestation.owner)
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! EIP-712 typed data for gasless, relayer-submitted verifications.
+
+use alloc::borrow::Cow;
+use alloy_sol_types::{sol, Eip712Domain, SolStruct};
+use stylus_sdk::alloy_primitives::{Address, B256, U256};
+
+/// EIP-712 domain name
+pub const DOMAIN_NAME: &str = "ArbiPic Verifier";
+/// EIP-712 domain version
+pub const DOMAIN_VERSION: &str = "1";
+
+sol! {
+    // Signed by the photo owner so a relayer can submit `verify_photo_with_sig`
+    struct VerifyPhoto {
+        address owner;
+        uint256 photoHash;
+        uint256 zkCommitment;
+        uint256 nonce;
+        uint256 deadline;
+    }
+}
+
+/// Domain binding signatures to this chain and contract
+pub fn domain(chain_id: u64, contract: Address) -> Eip712Domain {
+    Eip712Domain::new(
+        Some(Cow::Borrowed(DOMAIN_NAME)),
+        Some(Cow::Borrowed(DOMAIN_VERSION)),
+        Some(U256::from(chain_id)),
+        Some(contract),
+        None,
+    )
+}
+
+/// Digest the owner signs for a `VerifyPhoto` message
+pub fn verify_photo_digest(domain: &Eip712Domain, message: &VerifyPhoto) -> B256 {
+    message.eip712_signing_hash(domain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::alloy_primitives::{address, b256};
+
+    // `hashTypedData` of `buildVerifyPhotoTypedData` in frontend/src/utils/metaTx.ts
+    // on chain 421614, computed field by field from the EIP-712 encoding rules
+    const CONTRACT: Address = address!("5FbDB2315678afecb367f032d93F642f64180aa3");
+    const SEPARATOR: B256 = b256!("0ef48e6021360268063dd2f4c37a8dc8738a775f755b77c7b96393ab9464f203");
+    const DIGEST: B256 = b256!("218296682d2cea5011dc1143a1712a327acd913b73a2ecb5ae5bd797560d1be8");
+
+    fn message() -> VerifyPhoto {
+        VerifyPhoto {
+            owner: address!("70997970C51812dc3A010C7d01b50e0d17dc79C8"),
+            photoHash: U256::from(0x1234),
+            zkCommitment: U256::from(0x5678),
+            nonce: U256::ZERO,
+            deadline: U256::from(1_700_000_000),
+        }
+    }
+
+    #[test]
+    fn domain_separator_matches_typed_data() {
+        assert_eq!(domain(421614, CONTRACT).separator(), SEPARATOR);
+    }
+
+    #[test]
+    fn verify_photo_digest_matches_typed_data() {
+        assert_eq!(verify_photo_digest(&domain(421614, CONTRACT), &message()), DIGEST);
+    }
+
+    #[test]
+    fn digest_is_bound_to_chain_and_nonce() {
+        assert_ne!(verify_photo_digest(&domain(42161, CONTRACT), &message()), DIGEST);
+        let mut replay = message();
+        replay.nonce = U256::from(1);
+        assert_ne!(verify_photo_digest(&domain(421614, CONTRACT), &replay), DIGEST);
+    }
+}
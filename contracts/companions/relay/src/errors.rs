@@ -0,0 +1,22 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Typed revert reasons for the Relay contract.
+
+use alloy_sol_types::sol;
+use stylus_sdk::prelude::*;
+
+pub use arbipic_common::errors::{InvalidCommitment, InvalidSignature, VerifierRejected};
+
+sol! {
+    // Signed message is past its deadline
+    error SignatureExpired(uint256 deadline);
+}
+
+#[derive(SolidityError)]
+pub enum RelayError {
+    InvalidCommitment(InvalidCommitment),
+    SignatureExpired(SignatureExpired),
+    InvalidSignature(InvalidSignature),
+    VerifierRejected(VerifierRejected),
+}
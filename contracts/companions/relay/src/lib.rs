@@ -0,0 +1,100 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Gasless verification: a relayer submits a photo owner's EIP-712 signature
+//! and the Verifier records the photo for the signer.
+//!
+//! Signatures are made over this contract's domain and recorded through the
+//! Verifier's `recordPhoto`, which needs `COMPANION_ROLE`.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+
+extern crate alloc;
+
+mod eip712;
+mod errors;
+
+pub use errors::*;
+use arbipic_common::{
+    signature,
+    verifier::{self, IVerifier},
+};
+use stylus_sdk::storage::*;
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, B256, U256},
+    prelude::*,
+};
+
+#[storage]
+#[entrypoint]
+pub struct Relay {
+    // Verifier the signed photos are recorded on
+    verifier: StorageAddress,
+
+    // EIP-712 signature nonces per photo owner
+    nonces: StorageMap<Address, StorageU256>,
+}
+
+#[public]
+impl Relay {
+    /// Bind the relay to the Verifier it records photos on
+    #[constructor]
+    pub fn constructor(&mut self, verifier: Address) {
+        self.verifier.set(verifier);
+    }
+
+    /// Verify a photo on behalf of `owner` from their EIP-712 signature
+    /// Lets a relayer pay gas while the attestation is attributed to the signer;
+    /// smart-contract wallet owners are checked through ERC-1271
+    pub fn verify_photo_with_sig(
+        &mut self,
+        owner: Address,
+        photo_hash: U256,
+        zk_commitment: U256,
+        deadline: U256,
+        signature: Bytes,
+    ) -> Result<U256, RelayError> {
+        if zk_commitment == U256::ZERO {
+            return Err(RelayError::InvalidCommitment(InvalidCommitment { photo_hash }));
+        }
+        if U256::from(self.vm().block_timestamp()) > deadline {
+            return Err(RelayError::SignatureExpired(SignatureExpired { deadline }));
+        }
+
+        let nonce = self.nonces.get(owner);
+        let message = eip712::VerifyPhoto {
+            owner,
+            photoHash: photo_hash,
+            zkCommitment: zk_commitment,
+            nonce,
+            deadline,
+        };
+        let domain = eip712::domain(self.vm().chain_id(), self.vm().contract_address());
+        let digest = eip712::verify_photo_digest(&domain, &message);
+        if !signature::is_valid_signature_now(self.vm(), owner, digest, &signature) {
+            return Err(RelayError::InvalidSignature(InvalidSignature {}));
+        }
+        self.nonces.setter(owner).set(nonce + U256::from(1));
+
+        let config = Call::new_mutating(self);
+        IVerifier::new(self.verifier.get())
+            .record_photo(self.vm(), config, owner, photo_hash, zk_commitment, Bytes::default())
+            .map_err(|error| RelayError::VerifierRejected(verifier::rejected(error)))
+    }
+
+    /// Get the next EIP-712 nonce of a photo owner
+    pub fn get_nonce(&self, owner: Address) -> Result<U256, RelayError> {
+        Ok(self.nonces.get(owner))
+    }
+
+    /// Get the EIP-712 domain separator used by `verify_photo_with_sig`
+    pub fn domain_separator(&self) -> Result<B256, RelayError> {
+        Ok(eip712::domain(self.vm().chain_id(), self.vm().contract_address()).separator())
+    }
+
+    /// Get the Verifier signed photos are recorded on
+    pub fn get_verifier(&self) -> Result<Address, RelayError> {
+        Ok(self.verifier.get())
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Typed revert reasons for the Snark contract.
+
+use alloy_sol_types::sol;
+use stylus_sdk::prelude::*;
+
+pub use arbipic_common::errors::NotOwner;
+
+sol! {
+    // Groth16 verifying key has already been installed
+    error VerifyingKeyAlreadySet();
+    // Groth16 verifying key has not been installed yet
+    error VerifyingKeyNotSet();
+    // Groth16 verifying key has the wrong number of words
+    error InvalidVerifyingKey();
+}
+
+#[derive(SolidityError)]
+pub enum SnarkError {
+    NotOwner(NotOwner),
+    VerifyingKeyAlreadySet(VerifyingKeyAlreadySet),
+    VerifyingKeyNotSet(VerifyingKeyNotSet),
+    InvalidVerifyingKey(InvalidVerifyingKey),
+}
@@ -0,0 +1,11 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Logs emitted by the Snark contract.
+
+use alloy_sol_types::sol;
+
+sol! {
+    // The Groth16 verifying key was installed
+    event VerifyingKeySet(address indexed owner, bytes32 key_hash);
+}
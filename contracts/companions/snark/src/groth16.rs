@@ -0,0 +1,346 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Groth16 verification over BN254 using the EIP-196/197 precompiles.
+//!
+//! Points use the precompile encoding: G1 is `[x, y]`, G2 is
+//! `[x_im, x_re, y_im, y_re]`. A verifying key is flattened as
+//! `alpha (2) || beta (4) || gamma (4) || delta (4) || ic (2 * (inputs + 1))`
+//! and a proof as `a (2) || b (4) || c (2)`.
+
+use alloc::vec::Vec;
+use stylus_sdk::{
+    alloy_primitives::{address, uint, Address, U256},
+    call::RawCall,
+    prelude::*,
+};
+
+/// BN254 G1 point addition precompile
+const EC_ADD: Address = address!("0000000000000000000000000000000000000006");
+/// BN254 G1 scalar multiplication precompile
+const EC_MUL: Address = address!("0000000000000000000000000000000000000007");
+/// BN254 pairing check precompile
+const EC_PAIRING: Address = address!("0000000000000000000000000000000000000008");
+
+/// BN254 base field modulus
+pub const FIELD_MODULUS: U256 =
+    uint!(0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47_U256);
+
+/// BN254 scalar field modulus; public inputs must be below it
+pub const SCALAR_MODULUS: U256 =
+    uint!(0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001_U256);
+
+/// Public inputs of the photo ownership circuit: `photo_hash mod r`, `commitment`,
+/// `challenge mod r`
+pub const NUM_PUBLIC_INPUTS: usize = 3;
+
+/// Words in a flattened verifying key for the photo ownership circuit
+pub const VERIFYING_KEY_WORDS: usize = 2 + 4 + 4 + 4 + 2 * (NUM_PUBLIC_INPUTS + 1);
+
+/// Words in a flattened proof
+pub const PROOF_WORDS: usize = 8;
+
+/// Check a Groth16 proof against a flattened verifying key
+/// Returns false for out-of-range inputs, malformed points or a failed pairing
+pub fn verify<H: Host + ?Sized>(
+    host: &H,
+    vk: &[U256],
+    proof: &[U256; PROOF_WORDS],
+    inputs: &[U256; NUM_PUBLIC_INPUTS],
+) -> bool {
+    let precompile = |address: Address, input: &[u8]| unsafe { RawCall::new_static(host).call(address, input) }.ok();
+    verify_with(&precompile, vk, proof, inputs)
+}
+
+/// `verify` with the BN254 precompiles reached through `precompile`
+fn verify_with<P: Fn(Address, &[u8]) -> Option<Vec<u8>>>(
+    precompile: &P,
+    vk: &[U256],
+    proof: &[U256; PROOF_WORDS],
+    inputs: &[U256; NUM_PUBLIC_INPUTS],
+) -> bool {
+    if vk.len() != VERIFYING_KEY_WORDS || inputs.iter().any(|i| *i >= SCALAR_MODULUS) {
+        return false;
+    }
+    let (alpha, rest) = vk.split_at(2);
+    let (beta, rest) = rest.split_at(4);
+    let (gamma, rest) = rest.split_at(4);
+    let (delta, ic) = rest.split_at(4);
+
+    // vk_x = ic[0] + sum(inputs[i] * ic[i + 1])
+    let mut vk_x = [ic[0], ic[1]];
+    for (i, input) in inputs.iter().enumerate() {
+        let point = [ic[2 * i + 2], ic[2 * i + 3]];
+        let Some(term) = ec_mul(precompile, &point, *input) else {
+            return false;
+        };
+        let Some(sum) = ec_add(precompile, &vk_x, &term) else {
+            return false;
+        };
+        vk_x = sum;
+    }
+
+    // e(-a, b) * e(alpha, beta) * e(vk_x, gamma) * e(c, delta) == 1
+    let Some(neg_a) = negate(&[proof[0], proof[1]]) else {
+        return false;
+    };
+    let mut words = Vec::with_capacity(4 * 6);
+    words.extend_from_slice(&neg_a);
+    words.extend_from_slice(&proof[2..6]);
+    words.extend_from_slice(alpha);
+    words.extend_from_slice(beta);
+    words.extend_from_slice(&vk_x);
+    words.extend_from_slice(gamma);
+    words.extend_from_slice(&proof[6..8]);
+    words.extend_from_slice(delta);
+
+    match call_precompile(precompile, EC_PAIRING, &words) {
+        Some(output) if output.len() == 32 => output[31] == 1,
+        _ => false,
+    }
+}
+
+/// Negate a G1 point; the identity stays the identity
+fn negate(point: &[U256; 2]) -> Option<[U256; 2]> {
+    let [x, y] = *point;
+    if x >= FIELD_MODULUS || y >= FIELD_MODULUS {
+        return None;
+    }
+    if y == U256::ZERO {
+        return Some([x, y]);
+    }
+    Some([x, FIELD_MODULUS - y])
+}
+
+fn ec_add<P: Fn(Address, &[u8]) -> Option<Vec<u8>>>(precompile: &P, p: &[U256; 2], q: &[U256; 2]) -> Option<[U256; 2]> {
+    let output = call_precompile(precompile, EC_ADD, &[p[0], p[1], q[0], q[1]])?;
+    read_point(&output)
+}
+
+fn ec_mul<P: Fn(Address, &[u8]) -> Option<Vec<u8>>>(precompile: &P, p: &[U256; 2], scalar: U256) -> Option<[U256; 2]> {
+    let output = call_precompile(precompile, EC_MUL, &[p[0], p[1], scalar])?;
+    read_point(&output)
+}
+
+fn read_point(output: &[u8]) -> Option<[U256; 2]> {
+    if output.len() != 64 {
+        return None;
+    }
+    Some([
+        U256::from_be_slice(&output[..32]),
+        U256::from_be_slice(&output[32..]),
+    ])
+}
+
+fn call_precompile<P: Fn(Address, &[u8]) -> Option<Vec<u8>>>(precompile: &P, address: Address, words: &[U256]) -> Option<Vec<u8>> {
+    let mut input = Vec::with_capacity(words.len() * 32);
+    for word in words {
+        input.extend_from_slice(&word.to_be_bytes::<32>());
+    }
+    precompile(address, &input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Bn254, Fq, Fq2, G1Affine, G2Affine};
+    use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+    use ark_ff::{BigInteger, PrimeField, Zero};
+
+    // Groth16 setup and proof of the photo_ownership.circom statement, made with
+    // arkworks and circomlib-compatible Poseidon, written out in snarkjs
+    // `verification_key.json` / `proof.json` / `public.json` layout: G2
+    // coordinates are `[re, im]` there and get swapped when flattened
+    const VK_ALPHA_1: [&str; 2] = [
+        "825836194485588652717664358421543484768674338711972436985539453759841518721",
+        "8554301752179563250135022535641334324438337927088736377526337775597349620808",
+    ];
+    const VK_BETA_2: [[&str; 2]; 2] = [
+        [
+            "251597462155451000074824642696356071919765698183256376463317489421504968365",
+            "17000787663378829769869762696113744399740933025356098468251284323975259908903",
+        ],
+        [
+            "8691341940239641286686164295832411627603410408223571166750229014710903472946",
+            "9748236339084156919284388783116227051404426803319507772354003900049615270479",
+        ],
+    ];
+    const VK_GAMMA_2: [[&str; 2]; 2] = [
+        [
+            "1313770292357383578619229133232363445875507661996974073537701676607973212754",
+            "18254539486773619122711510054220344908349550792019586853210893362854117828988",
+        ],
+        [
+            "21318581946920905568215587106405799911655595978941131650051967199635789817868",
+            "15417333272876813589342090182186383528352801936360629847742042688154590650900",
+        ],
+    ];
+    const VK_DELTA_2: [[&str; 2]; 2] = [
+        [
+            "11611905576973617060356099017389159507182485176238896514611131837738121732539",
+            "8232082963728118139402782902207626898632544061578763325922604773791345391942",
+        ],
+        [
+            "21466676196926649701651328842366640504913547752198782440978370236233359381007",
+            "6890002449694197005649307337625555060332634938189753206633813489193361243331",
+        ],
+    ];
+    const IC: [[&str; 2]; 4] = [
+        [
+            "17487326144155340248335318021216607937354342492649944191271735124814671985129",
+            "7776302691288180488295318916410912389531680712007534989438973751424093838814",
+        ],
+        [
+            "9761692020948558938983946070376285652969478477348227994593719239938912035373",
+            "124468015099733993041702267327615108355098149267281654368514159046131894250",
+        ],
+        [
+            "16509801906449540530888656142474153577758004215780162334872354169815242537530",
+            "2929253495501208574448471525171805660207156819376021971420507785186925808704",
+        ],
+        [
+            "2531156867944414936372588746883018502648240868562301051483317848891518768829",
+            "4081095652688778001695884616978178333943535232337608684277778690056893134979",
+        ],
+    ];
+    const PI_A: [&str; 2] = [
+        "3231051282162280983870484280977145291796078367573684959676556710026188721354",
+        "2884868782883402118248992245263093077951426242536962511778923125181195680954",
+    ];
+    const PI_B: [[&str; 2]; 2] = [
+        [
+            "15078587712509727373377304469992885470015458760313000075791483866734753052751",
+            "4443897350181604616751118692821622960914798739268747461994945563552879602424",
+        ],
+        [
+            "13165244846802143508750532757160451864104958803317852745644679599611153635957",
+            "14743040725172894978904786330054723369883860618708316307671496267626588568198",
+        ],
+    ];
+    const PI_C: [&str; 2] = [
+        "4607156398990783515238904560025648423828191741700350931475205199518426117964",
+        "19329706060334218587810037233482048497966933916572424224148599803295758445266",
+    ];
+    // `[photoHash mod r, poseidon(photoHash mod r, secret), challenge]`
+    const PUBLIC_SIGNALS: [&str; 3] = [
+        "11459097284580423382280043268095992287032233447309522992854729417130357338560",
+        "2811843402609899634932258321314875620116784091115469889774124134759169010563",
+        "21631526949943588431043251722898144990346141784156736548686332258633855790589",
+    ];
+
+    fn word(decimal: &str) -> U256 {
+        U256::from_str_radix(decimal, 10).unwrap()
+    }
+
+    /// snarkjs `[[x_re, x_im], [y_re, y_im]]` to `[x_im, x_re, y_im, y_re]`
+    fn g2_words(point: &[[&str; 2]; 2]) -> [U256; 4] {
+        [word(point[0][1]), word(point[0][0]), word(point[1][1]), word(point[1][0])]
+    }
+
+    /// The flattening `circuits/README.md` documents for `setVerifyingKey`
+    fn verifying_key() -> Vec<U256> {
+        let mut vk = vec![word(VK_ALPHA_1[0]), word(VK_ALPHA_1[1])];
+        vk.extend(g2_words(&VK_BETA_2));
+        vk.extend(g2_words(&VK_GAMMA_2));
+        vk.extend(g2_words(&VK_DELTA_2));
+        vk.extend(IC.iter().flat_map(|point| [word(point[0]), word(point[1])]));
+        vk
+    }
+
+    /// `formatGroth16Proof` in `frontend/src/utils/zkProof.ts`
+    fn proof() -> [U256; PROOF_WORDS] {
+        let b = g2_words(&PI_B);
+        [word(PI_A[0]), word(PI_A[1]), b[0], b[1], b[2], b[3], word(PI_C[0]), word(PI_C[1])]
+    }
+
+    fn inputs() -> [U256; NUM_PUBLIC_INPUTS] {
+        PUBLIC_SIGNALS.map(word)
+    }
+
+    fn fq(bytes: &[u8]) -> Option<Fq> {
+        let value = U256::from_be_slice(bytes);
+        (value < FIELD_MODULUS).then(|| Fq::from_be_bytes_mod_order(bytes))
+    }
+
+    fn g1(bytes: &[u8]) -> Option<G1Affine> {
+        let (x, y) = (fq(&bytes[..32])?, fq(&bytes[32..64])?);
+        if x.is_zero() && y.is_zero() {
+            return Some(G1Affine::zero());
+        }
+        let point = G1Affine::new_unchecked(x, y);
+        point.is_on_curve().then_some(point)
+    }
+
+    fn g2(bytes: &[u8]) -> Option<G2Affine> {
+        let x = Fq2::new(fq(&bytes[32..64])?, fq(&bytes[..32])?);
+        let y = Fq2::new(fq(&bytes[96..128])?, fq(&bytes[64..96])?);
+        if x.is_zero() && y.is_zero() {
+            return Some(G2Affine::zero());
+        }
+        let point = G2Affine::new_unchecked(x, y);
+        (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()).then_some(point)
+    }
+
+    fn encode(point: G1Affine) -> Vec<u8> {
+        let Some((x, y)) = point.xy() else {
+            return vec![0; 64];
+        };
+        let mut output = x.into_bigint().to_bytes_be();
+        output.extend(y.into_bigint().to_bytes_be());
+        output
+    }
+
+    /// EIP-196/197 precompiles, as the chain runs them
+    fn precompile(address: Address, input: &[u8]) -> Option<Vec<u8>> {
+        match address {
+            EC_ADD if input.len() == 128 => Some(encode((g1(&input[..64])? + g1(&input[64..])?).into_affine())),
+            EC_MUL if input.len() == 96 => {
+                let scalar = ark_bn254::Fr::from_be_bytes_mod_order(&input[64..]);
+                Some(encode((g1(&input[..64])? * scalar).into_affine()))
+            }
+            EC_PAIRING if input.len() % 192 == 0 => {
+                let mut a = Vec::new();
+                let mut b = Vec::new();
+                for pair in input.chunks(192) {
+                    a.push(g1(&pair[..64])?);
+                    b.push(g2(&pair[64..])?);
+                }
+                let holds = Bn254::multi_pairing(a, b).is_zero();
+                let mut output = vec![0; 32];
+                output[31] = holds as u8;
+                Some(output)
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn verifies_the_fixture_proof() {
+        assert!(verify_with(&precompile, &verifying_key(), &proof(), &inputs()));
+    }
+
+    #[test]
+    fn rejects_other_public_inputs() {
+        for i in 0..NUM_PUBLIC_INPUTS {
+            let mut inputs = inputs();
+            inputs[i] += U256::from(1);
+            assert!(!verify_with(&precompile, &verifying_key(), &proof(), &inputs));
+        }
+    }
+
+    #[test]
+    fn rejects_g2_points_in_snarkjs_limb_order() {
+        let mut proof = proof();
+        proof.swap(2, 3);
+        proof.swap(4, 5);
+        assert!(!verify_with(&precompile, &verifying_key(), &proof, &inputs()));
+    }
+
+    #[test]
+    fn rejects_out_of_range_inputs() {
+        let mut inputs = inputs();
+        inputs[0] += SCALAR_MODULUS;
+        assert!(!verify_with(&precompile, &verifying_key(), &proof(), &inputs));
+        assert!(!verify_with(&precompile, &verifying_key()[1..], &proof(), &self::inputs()));
+    }
+}
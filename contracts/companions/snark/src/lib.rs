@@ -0,0 +1,147 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Groth16 ownership proofs: knowledge of the secret behind a photo's
+//! Poseidon commitment, proved without revealing it.
+//!
+//! Commitments are read from the Verifier; the verifying key is managed by
+//! the Verifier's owner.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+
+extern crate alloc;
+
+mod errors;
+mod events;
+mod groth16;
+
+pub use errors::*;
+pub use events::*;
+use alloc::vec::Vec;
+use arbipic_common::{log::emit, signature, verifier};
+use stylus_sdk::storage::*;
+use stylus_sdk::{
+    alloy_primitives::{Address, B256, U256},
+    crypto::keccak,
+    prelude::*,
+};
+
+#[storage]
+#[entrypoint]
+pub struct Snark {
+    // Verifier whose photos are proved
+    verifier: StorageAddress,
+
+    // Flattened Groth16 verifying key of the photo ownership circuit
+    snark_verifying_key: StorageVec<StorageU256>,
+}
+
+#[public]
+impl Snark {
+    /// Bind the contract to the Verifier whose photos it proves
+    #[constructor]
+    pub fn constructor(&mut self, verifier: Address) {
+        self.verifier.set(verifier);
+    }
+
+    /// Install the Groth16 verifying key of the photo ownership circuit
+    /// Set once by the Verifier's owner; changing it later takes `replace_verifying_key`
+    pub fn set_verifying_key(&mut self, verifying_key: Vec<U256>) -> Result<(), SnarkError> {
+        let owner = self.only_owner()?;
+        if !self.snark_verifying_key.is_empty() {
+            return Err(SnarkError::VerifyingKeyAlreadySet(VerifyingKeyAlreadySet {}));
+        }
+        self.store_verifying_key(owner, verifying_key)
+    }
+
+    /// Swap the installed verifying key for a new one, e.g. after a circuit change
+    /// Proofs made for the old key stop verifying
+    pub fn replace_verifying_key(&mut self, verifying_key: Vec<U256>) -> Result<(), SnarkError> {
+        let owner = self.only_owner()?;
+        if self.snark_verifying_key.is_empty() {
+            return Err(SnarkError::VerifyingKeyNotSet(VerifyingKeyNotSet {}));
+        }
+        self.store_verifying_key(owner, verifying_key)
+    }
+
+    /// Verify a Groth16 proof of knowledge of the secret behind a Poseidon commitment
+    /// The circuit proves `poseidon(photo_hash mod r, secret) == zk_commitment`
+    /// with public inputs `[photo_hash mod r, zk_commitment, digest mod r]`, where
+    /// `digest` is `ownership_challenge_digest` of the verifier's fresh `challenge`,
+    /// so a proof can't be replayed; the secret never leaves the prover.
+    /// Revoked photos never pass, nor do keccak commitments made for `verify_zk_proof`
+    pub fn verify_snark_proof(&self, photo_hash: U256, challenge: B256, proof: [U256; 8]) -> Result<bool, SnarkError> {
+        if self.snark_verifying_key.is_empty() {
+            return Err(SnarkError::VerifyingKeyNotSet(VerifyingKeyNotSet {}));
+        }
+        let attestation = verifier::attestation(self.vm(), self.verifier.get(), photo_hash);
+        if !attestation.is_active() || attestation.zk_commitment == U256::ZERO {
+            return Ok(false);
+        }
+
+        let verifying_key = self.load_verifying_key();
+        let digest = self.ownership_challenge_digest(photo_hash, challenge)?;
+        let inputs = [
+            photo_hash % groth16::SCALAR_MODULUS,
+            attestation.zk_commitment,
+            U256::from_be_bytes(digest.0) % groth16::SCALAR_MODULUS,
+        ];
+        Ok(groth16::verify(self.vm(), &verifying_key, &proof, &inputs))
+    }
+
+    /// Digest of `challenge` for `photo_hash` that a proof is bound to
+    pub fn ownership_challenge_digest(&self, photo_hash: U256, challenge: B256) -> Result<B256, SnarkError> {
+        Ok(signature::ownership_challenge_digest(
+            self.vm().chain_id(),
+            self.vm().contract_address(),
+            photo_hash,
+            challenge,
+        ))
+    }
+
+    /// Get the flattened Groth16 verifying key (empty until set)
+    pub fn get_verifying_key(&self) -> Result<Vec<U256>, SnarkError> {
+        Ok(self.load_verifying_key())
+    }
+
+    /// Get the Verifier whose photos are proved
+    pub fn get_verifier(&self) -> Result<Address, SnarkError> {
+        Ok(self.verifier.get())
+    }
+}
+
+impl Snark {
+    /// Overwrite the stored Groth16 verifying key with a well-formed one
+    fn store_verifying_key(&mut self, owner: Address, verifying_key: Vec<U256>) -> Result<(), SnarkError> {
+        if verifying_key.len() != groth16::VERIFYING_KEY_WORDS {
+            return Err(SnarkError::InvalidVerifyingKey(InvalidVerifyingKey {}));
+        }
+        while self.snark_verifying_key.pop().is_some() {}
+
+        let mut packed = Vec::with_capacity(verifying_key.len() * 32);
+        for word in verifying_key {
+            self.snark_verifying_key.push(word);
+            packed.extend_from_slice(&word.to_be_bytes::<32>());
+        }
+
+        emit(self.vm(), VerifyingKeySet { owner, key_hash: keccak(packed) });
+        Ok(())
+    }
+
+    /// Read the flattened Groth16 verifying key out of storage
+    fn load_verifying_key(&self) -> Vec<U256> {
+        (0..self.snark_verifying_key.len())
+            .filter_map(|i| self.snark_verifying_key.get(i))
+            .collect()
+    }
+
+    /// Revert unless the caller owns the Verifier; returns the owner
+    fn only_owner(&self) -> Result<Address, SnarkError> {
+        let sender = self.vm().msg_sender();
+        let owner = verifier::contract_owner(self.vm(), self.verifier.get());
+        if owner == Address::ZERO || sender != owner {
+            return Err(SnarkError::NotOwner(NotOwner { caller: sender }));
+        }
+        Ok(owner)
+    }
+}
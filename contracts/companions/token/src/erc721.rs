@@ -0,0 +1,119 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! ERC-721 view of the attestations, with ERC-2981 royalties.
+//!
+//! Token ID is the photo hash and the token owner is the attestation owner.
+//! Verification mints, revocation burns, and `transfer_photo` and the
+//! ERC-721 transfers move the same record.
+
+use alloc::{string::String, vec::Vec};
+use stylus_sdk::{
+    alloy_primitives::{FixedBytes, U256},
+    prelude::*,
+};
+
+pub const NAME: &str = "ArbiPic Verified Photo";
+pub const SYMBOL: &str = "APIC";
+
+/// 32-byte words kept per CID, enough for any CID `verifyPhotoWithDetails` accepts
+pub const CID_WORDS: usize = 4;
+
+/// Denominator of royalty fees, in basis points
+pub const FEE_DENOMINATOR: u16 = 10_000;
+
+/// ERC-165 interface IDs answered by `supports_interface`
+const INTERFACE_ERC165: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+const INTERFACE_ERC721: [u8; 4] = [0x80, 0xac, 0x58, 0xcd];
+const INTERFACE_ERC721_METADATA: [u8; 4] = [0x5b, 0x5e, 0x13, 0x9f];
+const INTERFACE_ERC2981: [u8; 4] = [0x2a, 0x55, 0x20, 0x5a];
+
+/// `onERC721Received.selector`, returned by receivers accepting a token
+pub const RECEIVED: [u8; 4] = [0x15, 0x0b, 0x7a, 0x02];
+
+sol_interface! {
+    interface IERC721Receiver {
+        function onERC721Received(address operator, address from, uint256 token_id, bytes data) external returns (bytes4);
+    }
+}
+
+pub fn supports_interface(interface_id: FixedBytes<4>) -> bool {
+    matches!(
+        interface_id.0,
+        INTERFACE_ERC165 | INTERFACE_ERC721 | INTERFACE_ERC721_METADATA | INTERFACE_ERC2981
+    )
+}
+
+/// Base64 alphabet of RFC 4648, used by `token_uri`
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// `data:application/json;base64,` URI of the token metadata
+/// `image` is `ipfs://<cid>`; it is left out unless the CID is plain ASCII
+/// letters and digits, so nothing needs escaping in the JSON
+pub fn token_uri(cid: &[u8]) -> String {
+    let mut json = Vec::from(br#"{"name":""#.as_slice());
+    json.extend_from_slice(NAME.as_bytes());
+    if !cid.is_empty() && cid.iter().all(u8::is_ascii_alphanumeric) {
+        json.extend_from_slice(br#"","image":"ipfs://"#);
+        json.extend_from_slice(cid);
+    }
+    json.extend_from_slice(br#""}"#);
+
+    let mut uri = String::from("data:application/json;base64,");
+    uri.push_str(&base64(&json));
+    uri
+}
+
+/// Padded RFC 4648 base64
+fn base64(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let word = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64[(word >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Royalty owed on `sale_price` at `fee_bps`
+pub fn royalty_amount(sale_price: U256, fee_bps: u16) -> U256 {
+    sale_price * U256::from(fee_bps) / U256::from(FEE_DENOMINATOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_pads_partial_chunks() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn token_uri_points_image_at_ipfs() {
+        // {"name":"ArbiPic Verified Photo","image":"ipfs://bafy123"}
+        assert_eq!(
+            token_uri(b"bafy123"),
+            "data:application/json;base64,\
+             eyJuYW1lIjoiQXJiaVBpYyBWZXJpZmllZCBQaG90byIsImltYWdlIjoiaXBmczovL2JhZnkxMjMifQ=="
+        );
+    }
+
+    #[test]
+    fn token_uri_leaves_out_unsafe_cids() {
+        // {"name":"ArbiPic Verified Photo"}
+        let bare = "data:application/json;base64,eyJuYW1lIjoiQXJiaVBpYyBWZXJpZmllZCBQaG90byJ9";
+        assert_eq!(token_uri(b""), bare);
+        assert_eq!(token_uri(br#"Qm","x":""#), bare);
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Typed revert reasons for the photo token.
+
+use alloy_sol_types::sol;
+use stylus_sdk::prelude::*;
+
+pub use arbipic_common::errors::{EnforcedPause, NotOwner, NotVerifier, VerifierRejected};
+
+sol! {
+    // ERC-721 (ERC-6093): balance queried for the zero address
+    error ERC721InvalidOwner(address owner);
+    // ERC-721 (ERC-6093): token was never minted or is revoked
+    error ERC721NonexistentToken(uint256 token_id);
+    // ERC-721 (ERC-6093): `from` doesn't own the token
+    error ERC721IncorrectOwner(address sender, uint256 token_id, address owner);
+    // ERC-721 (ERC-6093): zero address or a contract that rejected the token
+    error ERC721InvalidReceiver(address receiver);
+    // ERC-721 (ERC-6093): caller is neither owner, approved nor operator
+    error ERC721InsufficientApproval(address operator, uint256 token_id);
+    // ERC-721 (ERC-6093): caller may not approve for this token
+    error ERC721InvalidApprover(address approver);
+    // ERC-721 (ERC-6093): the zero address can't be an operator
+    error ERC721InvalidOperator(address operator);
+    // Royalty fee above 100% or charged to the zero address
+    error InvalidRoyalty(address receiver, uint16 fee_bps);
+}
+
+#[derive(SolidityError)]
+pub enum TokenError {
+    NotOwner(NotOwner),
+    NotVerifier(NotVerifier),
+    EnforcedPause(EnforcedPause),
+    ERC721InvalidOwner(ERC721InvalidOwner),
+    ERC721NonexistentToken(ERC721NonexistentToken),
+    ERC721IncorrectOwner(ERC721IncorrectOwner),
+    ERC721InvalidReceiver(ERC721InvalidReceiver),
+    ERC721InsufficientApproval(ERC721InsufficientApproval),
+    ERC721InvalidApprover(ERC721InvalidApprover),
+    ERC721InvalidOperator(ERC721InvalidOperator),
+    InvalidRoyalty(InvalidRoyalty),
+    VerifierRejected(VerifierRejected),
+}
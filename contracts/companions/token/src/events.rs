@@ -0,0 +1,17 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Logs emitted by the photo token.
+
+use alloy_sol_types::sol;
+
+sol! {
+    // ERC-721: a photo token was minted, moved or burned (revoked)
+    event Transfer(address indexed from, address indexed to, uint256 indexed token_id);
+    // ERC-721: an address may transfer one photo token
+    event Approval(address indexed owner, address indexed approved, uint256 indexed token_id);
+    // ERC-721: an operator may transfer every photo token of an owner
+    event ApprovalForAll(address indexed owner, address indexed operator, bool approved);
+    // The ERC-2981 royalty of every photo changed
+    event DefaultRoyaltyUpdated(address indexed receiver, uint16 fee_bps, address indexed sender);
+}
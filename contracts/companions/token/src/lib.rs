@@ -0,0 +1,303 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! ERC-721 and ERC-2981 view of the Verifier's attestations.
+//!
+//! Ownership is read from the Verifier; approvals and the royalty live here.
+//! ERC-721 transfers move the attestation through the Verifier's `movePhoto`,
+//! which needs `COMPANION_ROLE`. As a Verifier hook, the token logs `Transfer`
+//! for every mint, transfer and revocation made elsewhere, and keeps the CID
+//! behind `tokenURI`.
+
+#![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+
+extern crate alloc;
+
+mod erc721;
+mod errors;
+mod events;
+
+pub use errors::*;
+pub use events::*;
+use alloc::{string::String, vec::Vec};
+use arbipic_common::{
+    log::emit,
+    verifier::{self, IVerifier},
+};
+use stylus_sdk::storage::*;
+use stylus_sdk::{
+    abi::Bytes,
+    alloy_primitives::{Address, FixedBytes, B256, U16, U256, U8},
+    prelude::*,
+};
+
+// CID behind `tokenURI`, in fixed words
+#[storage]
+pub struct Cid {
+    length: StorageU8,            // Length in bytes; zero without a CID
+    words: StorageArray<StorageB256, { erc721::CID_WORDS }>,
+}
+
+#[storage]
+#[entrypoint]
+pub struct Token {
+    // Verifier whose attestations are the tokens
+    verifier: StorageAddress,
+
+    // IPFS CIDs given at verification: photoHash => CID
+    cids: StorageMap<U256, Cid>,
+
+    // ERC-721 single-token approvals: photoHash => approved address
+    token_approvals: StorageMap<U256, StorageAddress>,
+
+    // ERC-721 operators: owner => operator => approved
+    operator_approvals: StorageMap<Address, StorageMap<Address, StorageBool>>,
+
+    // ERC-2981 royalty receiver and fee in basis points, for every photo
+    royalty_receiver: StorageAddress,
+    royalty_fee: StorageU16,
+}
+
+#[public]
+impl Token {
+    /// Bind the token to the Verifier whose attestations it represents
+    #[constructor]
+    pub fn constructor(&mut self, verifier: Address) {
+        self.verifier.set(verifier);
+    }
+
+    /// Verifier hook: log the mint, transfer or burn and drop the approval
+    pub fn on_photo_moved(
+        &mut self,
+        photo_hash: U256,
+        from: Address,
+        to: Address,
+        _zk_commitment: U256,
+        cid: Bytes,
+    ) -> Result<(), TokenError> {
+        let sender = self.vm().msg_sender();
+        if sender != self.verifier.get() {
+            return Err(TokenError::NotVerifier(NotVerifier { caller: sender }));
+        }
+        if from == Address::ZERO {
+            self.store_cid(photo_hash, &cid);
+        } else {
+            self.token_approvals.setter(photo_hash).set(Address::ZERO);
+        }
+
+        emit(self.vm(), Transfer { from, to, token_id: photo_hash });
+        Ok(())
+    }
+
+    /// ERC-721 collection name
+    pub fn name(&self) -> Result<String, TokenError> {
+        Ok(erc721::NAME.into())
+    }
+
+    /// ERC-721 collection symbol
+    pub fn symbol(&self) -> Result<String, TokenError> {
+        Ok(erc721::SYMBOL.into())
+    }
+
+    /// `data:application/json` metadata whose `image` is `ipfs://<cid>`, for the
+    /// CID given at verification; without one the metadata has no image
+    #[selector(name = "tokenURI")]
+    pub fn token_uri(&self, token_id: U256) -> Result<String, TokenError> {
+        self.token_owner(token_id)?;
+        let stored = self.cids.getter(token_id);
+        let mut cid: Vec<u8> = (0..erc721::CID_WORDS)
+            .filter_map(|i| stored.words.get(i))
+            .flat_map(|word| word.0)
+            .collect();
+        cid.truncate(stored.length.get().saturating_to::<usize>());
+        Ok(erc721::token_uri(&cid))
+    }
+
+    /// Number of active photos owned by `owner`
+    pub fn balance_of(&self, owner: Address) -> Result<U256, TokenError> {
+        if owner == Address::ZERO {
+            return Err(TokenError::ERC721InvalidOwner(ERC721InvalidOwner { owner }));
+        }
+        Ok(IVerifier::new(self.verifier.get())
+            .get_owner_photo_count(self.vm(), Call::new(), owner)
+            .unwrap_or_default())
+    }
+
+    /// Owner of the active attestation `token_id`
+    pub fn owner_of(&self, token_id: U256) -> Result<Address, TokenError> {
+        self.token_owner(token_id)
+    }
+
+    /// Address allowed to transfer `token_id` on its owner's behalf
+    pub fn get_approved(&self, token_id: U256) -> Result<Address, TokenError> {
+        self.token_owner(token_id)?;
+        Ok(self.token_approvals.get(token_id))
+    }
+
+    /// Check if `operator` may transfer every photo of `owner`
+    pub fn is_approved_for_all(&self, owner: Address, operator: Address) -> Result<bool, TokenError> {
+        Ok(self.operator_approvals.getter(owner).get(operator))
+    }
+
+    /// Let `to` transfer `token_id`; cleared by every transfer
+    pub fn approve(&mut self, to: Address, token_id: U256) -> Result<(), TokenError> {
+        self.when_not_paused()?;
+        let owner = self.token_owner(token_id)?;
+        let sender = self.vm().msg_sender();
+        if sender != owner && !self.operator_approvals.getter(owner).get(sender) {
+            return Err(TokenError::ERC721InvalidApprover(ERC721InvalidApprover { approver: sender }));
+        }
+        self.token_approvals.setter(token_id).set(to);
+
+        emit(self.vm(), Approval { owner, approved: to, token_id });
+        Ok(())
+    }
+
+    /// Let `operator` transfer every photo of the caller
+    pub fn set_approval_for_all(&mut self, operator: Address, approved: bool) -> Result<(), TokenError> {
+        self.when_not_paused()?;
+        if operator == Address::ZERO {
+            return Err(TokenError::ERC721InvalidOperator(ERC721InvalidOperator { operator }));
+        }
+        let owner = self.vm().msg_sender();
+        self.operator_approvals.setter(owner).setter(operator).set(approved);
+
+        emit(self.vm(), ApprovalForAll { owner, operator, approved });
+        Ok(())
+    }
+
+    /// ERC-721 transfer; moves the attestation on the Verifier
+    /// The seller knows the commitment's secret, so it is cleared and ZK proofs
+    /// fail until the new owner sets their own with `update_commitment`
+    pub fn transfer_from(&mut self, from: Address, to: Address, token_id: U256) -> Result<(), TokenError> {
+        self.transfer_token(from, to, token_id)
+    }
+
+    /// `transfer_from` that a contract recipient must accept
+    #[selector(name = "safeTransferFrom")]
+    pub fn safe_transfer_from(&mut self, from: Address, to: Address, token_id: U256) -> Result<(), TokenError> {
+        self.safe_transfer_from_with_data(from, to, token_id, Bytes::default())
+    }
+
+    /// `safe_transfer_from` passing `data` to the recipient
+    #[selector(name = "safeTransferFrom")]
+    pub fn safe_transfer_from_with_data(
+        &mut self,
+        from: Address,
+        to: Address,
+        token_id: U256,
+        data: Bytes,
+    ) -> Result<(), TokenError> {
+        self.transfer_token(from, to, token_id)?;
+        self.check_received(from, to, token_id, data)
+    }
+
+    /// ERC-165: ERC-721, ERC-721 metadata and ERC-2981
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> Result<bool, TokenError> {
+        Ok(erc721::supports_interface(interface_id))
+    }
+
+    /// ERC-2981 royalty on a sale: `(receiver, amount)`
+    pub fn royalty_info(&self, _token_id: U256, sale_price: U256) -> Result<(Address, U256), TokenError> {
+        let fee = self.royalty_fee.get().saturating_to::<u16>();
+        Ok((self.royalty_receiver.get(), erc721::royalty_amount(sale_price, fee)))
+    }
+
+    /// Set the royalty of every photo, in basis points; zero `receiver` and fee disable it
+    /// Verifier owner only, so a delegated admin can't redirect royalties
+    pub fn set_default_royalty(&mut self, receiver: Address, fee_bps: u16) -> Result<(), TokenError> {
+        let sender = self.vm().msg_sender();
+        let owner = verifier::contract_owner(self.vm(), self.verifier.get());
+        if owner == Address::ZERO || sender != owner {
+            return Err(TokenError::NotOwner(NotOwner { caller: sender }));
+        }
+        if fee_bps > erc721::FEE_DENOMINATOR || (receiver == Address::ZERO && fee_bps > 0) {
+            return Err(TokenError::InvalidRoyalty(InvalidRoyalty { receiver, fee_bps }));
+        }
+        self.royalty_receiver.set(receiver);
+        self.royalty_fee.set(U16::from(fee_bps));
+
+        emit(self.vm(), DefaultRoyaltyUpdated { receiver, fee_bps, sender });
+        Ok(())
+    }
+
+    /// Get the Verifier whose attestations are the tokens
+    pub fn get_verifier(&self) -> Result<Address, TokenError> {
+        Ok(self.verifier.get())
+    }
+}
+
+impl Token {
+    /// Keep the CID of a new photo for `tokenURI`; one too long to keep is dropped
+    fn store_cid(&mut self, photo_hash: U256, cid: &[u8]) {
+        if cid.len() > erc721::CID_WORDS * 32 {
+            return;
+        }
+        let mut stored = self.cids.setter(photo_hash);
+        stored.length.set(U8::from(cid.len()));
+        for (i, chunk) in cid.chunks(32).enumerate() {
+            if let Some(mut word) = stored.words.setter(i) {
+                word.set(B256::right_padding_from(chunk));
+            }
+        }
+    }
+
+    /// Owner of an active attestation; revoked photos count as burned tokens
+    fn token_owner(&self, token_id: U256) -> Result<Address, TokenError> {
+        verifier::active_owner(self.vm(), self.verifier.get(), token_id)
+            .ok_or(TokenError::ERC721NonexistentToken(ERC721NonexistentToken { token_id }))
+    }
+
+    /// ERC-721 transfer by the owner, the approved address or an operator
+    fn transfer_token(&mut self, from: Address, to: Address, token_id: U256) -> Result<(), TokenError> {
+        let owner = self.token_owner(token_id)?;
+        if owner != from {
+            return Err(TokenError::ERC721IncorrectOwner(ERC721IncorrectOwner { sender: from, token_id, owner }));
+        }
+        if to == Address::ZERO {
+            return Err(TokenError::ERC721InvalidReceiver(ERC721InvalidReceiver { receiver: to }));
+        }
+        let sender = self.vm().msg_sender();
+        if sender != owner
+            && self.token_approvals.get(token_id) != sender
+            && !self.operator_approvals.getter(owner).get(sender)
+        {
+            return Err(TokenError::ERC721InsufficientApproval(ERC721InsufficientApproval { operator: sender, token_id }));
+        }
+
+        // The Verifier checks its pause and tells every other hook
+        let verifier = self.verifier.get();
+        let config = Call::new_mutating(self);
+        IVerifier::new(verifier)
+            .move_photo(self.vm(), config, token_id, from, to)
+            .map_err(|error| TokenError::VerifierRejected(verifier::rejected(error)))?;
+        self.token_approvals.setter(token_id).set(Address::ZERO);
+
+        emit(self.vm(), Transfer { from, to, token_id });
+        Ok(())
+    }
+
+    /// Revert unless a contract recipient returns `onERC721Received.selector`
+    fn check_received(&mut self, from: Address, to: Address, token_id: U256, data: Bytes) -> Result<(), TokenError> {
+        if self.vm().code_size(to) == 0 {
+            return Ok(());
+        }
+        let operator = self.vm().msg_sender();
+        let config = Call::new_mutating(self);
+        let accepted = erc721::IERC721Receiver::new(to)
+            .on_erc_721_received(self.vm(), config, operator, from, token_id, data)
+            .is_ok_and(|selector| selector.0 == erc721::RECEIVED);
+        if !accepted {
+            return Err(TokenError::ERC721InvalidReceiver(ERC721InvalidReceiver { receiver: to }));
+        }
+        Ok(())
+    }
+
+    /// Revert while the Verifier is paused
+    fn when_not_paused(&self) -> Result<(), TokenError> {
+        if verifier::is_paused(self.vm(), self.verifier.get()) {
+            return Err(TokenError::EnforcedPause(EnforcedPause {}));
+        }
+        Ok(())
+    }
+}
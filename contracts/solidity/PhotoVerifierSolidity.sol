@@ -27,9 +27,20 @@ contract PhotoVerifierSolidity {
     // Total photos verified
     uint256 public photoCount;
 
+    event PhotoVerified(uint256 indexed photoHash, address indexed owner, uint256 zkCommitment, uint256 timestamp);
+    event CommitmentUpdated(uint256 indexed photoHash, address indexed owner, uint256 zkCommitment);
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+
+    error AlreadyOwnedBySender(uint256 photoHash);
+    error AlreadyVerified(uint256 photoHash, address owner);
+    error PhotoNotVerified(uint256 photoHash);
+    error NotPhotoOwner(uint256 photoHash, address owner);
+    error InvalidCommitment(uint256 photoHash);
+
     constructor() {
         owner = msg.sender;
         photoCount = 0;
+        emit OwnershipTransferred(address(0), msg.sender);
     }
 
     /**
@@ -39,8 +50,16 @@ contract PhotoVerifierSolidity {
      * @return timestamp The block timestamp when verified
      */
     function verifyPhoto(uint256 photoHash, uint256 zkCommitment) external returns (uint256) {
+        if (zkCommitment == 0) revert InvalidCommitment(photoHash);
         uint256 timestamp = block.timestamp;
         
+        // Refuse to overwrite an existing attestation
+        PhotoAttestation storage existing = attestations[photoHash];
+        if (existing.verifiedAt > 0) {
+            if (existing.owner == msg.sender) revert AlreadyOwnedBySender(photoHash);
+            revert AlreadyVerified(photoHash, existing.owner);
+        }
+        
         // Store attestation
         attestations[photoHash] = PhotoAttestation({
             verifiedAt: timestamp,
@@ -54,9 +73,22 @@ contract PhotoVerifierSolidity {
         // Increment total counter
         photoCount++;
         
+        emit PhotoVerified(photoHash, msg.sender, zkCommitment, timestamp);
         return timestamp;
     }
 
+    /**
+     * @dev Replace the ZK commitment of a photo the caller already owns
+     */
+    function updateCommitment(uint256 photoHash, uint256 zkCommitment) external {
+        if (zkCommitment == 0) revert InvalidCommitment(photoHash);
+        PhotoAttestation storage att = attestations[photoHash];
+        if (att.verifiedAt == 0) revert PhotoNotVerified(photoHash);
+        if (att.owner != msg.sender) revert NotPhotoOwner(photoHash, att.owner);
+        att.zkCommitment = zkCommitment;
+        emit CommitmentUpdated(photoHash, msg.sender, zkCommitment);
+    }
+
     /**
      * @dev Get attestation for a photo
      */
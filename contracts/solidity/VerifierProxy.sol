@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+/**
+ * @title VerifierProxy
+ * @dev Minimal ERC-1967 proxy for the upgradeable (UUPS) Stylus Verifier
+ *
+ * The proxy holds all attestations and keeps one address forever; upgrades are
+ * performed by the Verifier itself through `upgradeToAndCall`.
+ */
+contract VerifierProxy {
+    // bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)
+    bytes32 private constant IMPLEMENTATION_SLOT =
+        0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc;
+
+    event Upgraded(address indexed implementation);
+
+    /// @param implementation Deployed Stylus Verifier
+    /// @param data Initialization call, normally `init()` from the future owner's deployer
+    constructor(address implementation, bytes memory data) {
+        require(implementation.code.length > 0, "VerifierProxy: not a contract");
+        assembly {
+            sstore(IMPLEMENTATION_SLOT, implementation)
+        }
+        emit Upgraded(implementation);
+
+        if (data.length > 0) {
+            (bool success, bytes memory result) = implementation.delegatecall(data);
+            if (!success) {
+                assembly {
+                    revert(add(result, 32), mload(result))
+                }
+            }
+        }
+    }
+
+    fallback() external payable {
+        assembly {
+            let implementation := sload(IMPLEMENTATION_SLOT)
+            calldatacopy(0, 0, calldatasize())
+            let success := delegatecall(gas(), implementation, 0, calldatasize(), 0, 0)
+            returndatacopy(0, 0, returndatasize())
+            switch success
+            case 0 { revert(0, returndatasize()) }
+            default { return(0, returndatasize()) }
+        }
+    }
+
+    receive() external payable {}
+}
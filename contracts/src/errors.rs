@@ -0,0 +1,77 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Typed revert reasons for the Verifier contract.
+//! Each error is ABI-encoded with its Solidity selector so clients can decode it.
+
+use alloy_sol_types::sol;
+use stylus_sdk::prelude::*;
+
+pub use arbipic_common::errors::{
+    AttestationIsRevoked, EnforcedPause, InvalidCommitment, MissingRole, NotOwner, NotPhotoOwner, PhotoNotVerified,
+};
+
+sol! {
+    // Photo hash is already attested and owned by the caller
+    error AlreadyOwnedBySender(uint256 photo_hash);
+    // Photo hash is already attested by another address
+    error AlreadyVerified(uint256 photo_hash, address owner);
+    // Contract has not been initialized yet
+    error NotInitialized();
+    // Contract has already been initialized
+    error AlreadyInitialized();
+    // Caller is not the nominee of a pending ownership transfer
+    error NotPendingOwner(address caller);
+    // Zero address can't own the contract
+    error InvalidOwner(address owner);
+    // Photo can't be transferred to the zero address or its current owner
+    error InvalidRecipient(uint256 photo_hash, address recipient);
+    // Revocation reason code is zero or unknown
+    error InvalidReasonCode(uint8 reason);
+    // Contract is not paused
+    error ExpectedPause();
+    // Role id is not one of the Verifier roles
+    error UnknownRole(bytes32 role);
+    // Called directly on the implementation, or through a proxy where disallowed
+    error UnauthorizedCallContext();
+    // New implementation doesn't report the ERC-1967 slot as its proxiable UUID
+    error InvalidImplementation(address implementation);
+    // Post-upgrade call on the new implementation reverted
+    error UpgradeCallFailed(address implementation);
+    // Storage is already at or past this implementation's version
+    error AlreadyMigrated(uint64 version);
+    // Hook has no code
+    error InvalidHook(address hook);
+    // More hooks than `MAX_HOOKS`
+    error TooManyHooks(uint256 count);
+    // A hook reverted while being told about a photo
+    error HookFailed(address hook, uint256 photo_hash);
+}
+
+#[derive(SolidityError)]
+pub enum VerifierError {
+    AlreadyOwnedBySender(AlreadyOwnedBySender),
+    AlreadyVerified(AlreadyVerified),
+    PhotoNotVerified(PhotoNotVerified),
+    NotPhotoOwner(NotPhotoOwner),
+    NotOwner(NotOwner),
+    NotInitialized(NotInitialized),
+    AlreadyInitialized(AlreadyInitialized),
+    InvalidCommitment(InvalidCommitment),
+    NotPendingOwner(NotPendingOwner),
+    InvalidOwner(InvalidOwner),
+    InvalidRecipient(InvalidRecipient),
+    AttestationIsRevoked(AttestationIsRevoked),
+    InvalidReasonCode(InvalidReasonCode),
+    EnforcedPause(EnforcedPause),
+    ExpectedPause(ExpectedPause),
+    MissingRole(MissingRole),
+    UnknownRole(UnknownRole),
+    UnauthorizedCallContext(UnauthorizedCallContext),
+    InvalidImplementation(InvalidImplementation),
+    UpgradeCallFailed(UpgradeCallFailed),
+    AlreadyMigrated(AlreadyMigrated),
+    InvalidHook(InvalidHook),
+    TooManyHooks(TooManyHooks),
+    HookFailed(HookFailed),
+}
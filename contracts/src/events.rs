@@ -0,0 +1,40 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! Logs emitted on every Verifier state change.
+//! Indexers can rebuild the full attestation history from these alone.
+
+use alloy_sol_types::sol;
+
+sol! {
+    // A photo hash was attested for the first time
+    event PhotoVerified(uint256 indexed photo_hash, address indexed owner, uint256 zk_commitment, uint256 timestamp);
+    // The owner replaced the ZK commitment of an attested photo
+    event CommitmentUpdated(uint256 indexed photo_hash, address indexed owner, uint256 zk_commitment);
+    // A photo attestation moved to a new owner
+    event PhotoTransferred(uint256 indexed photo_hash, address indexed from, address indexed to, uint256 zk_commitment);
+    // An attestation was revoked by its owner or the contract admin
+    event AttestationRevoked(uint256 indexed photo_hash, address indexed revoked_by, uint8 reason, uint256 timestamp);
+    // A contract was added to or removed from the hooks told about every mint, transfer and revocation
+    event HookUpdated(address indexed hook, bool enabled);
+    // The proxy was pointed at a new implementation (ERC-1967)
+    event Upgraded(address indexed implementation);
+    // Storage was migrated to a new layout version
+    event StorageMigrated(uint64 from_version, uint64 to_version);
+    // A role was granted
+    event RoleGranted(bytes32 indexed role, address indexed account, address indexed sender);
+    // A role was revoked or renounced
+    event RoleRevoked(bytes32 indexed role, address indexed account, address indexed sender);
+    // Permissioned mode was switched on or off
+    event PermissionedModeSet(bool enabled, address indexed sender);
+    // User-facing writes were paused
+    event Paused(address account);
+    // User-facing writes were resumed
+    event Unpaused(address account);
+    // The contract was initialized
+    event ContractInitialized(address indexed owner);
+    // A two-step ownership transfer was started
+    event OwnershipTransferStarted(address indexed previous_owner, address indexed new_owner);
+    // The contract owner changed
+    event OwnershipTransferred(address indexed previous_owner, address indexed new_owner);
+}
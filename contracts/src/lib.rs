@@ -2,13 +2,24 @@
 // For licensing, see MIT OR Apache-2.0
 
 #![cfg_attr(not(any(test, feature = "export-abi")), no_main)]
+// The exported ABI chains one iterator per public method
+#![recursion_limit = "256"]
 
 extern crate alloc;
 
-use alloc::vec::Vec;
+mod errors;
+mod events;
+mod upgrade;
+
+pub use arbipic_common::roles::{self, *};
+use arbipic_common::{log::emit, verifier::IPhotoHook};
+pub use errors::*;
+pub use events::*;
 use stylus_sdk::storage::*;
+use alloc::vec::Vec;
 use stylus_sdk::{
-    alloy_primitives::{Address, U256},
+    abi::Bytes,
+    alloy_primitives::{Address, B256, U256, U64, U8},
     prelude::*,
 };
 
@@ -18,8 +29,25 @@ pub struct PhotoAttestation {
     verified_at: StorageU256,     // Block timestamp when verified
     owner: StorageAddress,        // Photo owner address
     zk_commitment: StorageU256,   // ZK commitment for ownership proof
+    revoked_at: StorageU256,      // Block timestamp when revoked, zero while active
+    revocation_reason: StorageU8, // Reason code given at revocation
 }
 
+/// Attestation status codes returned by `get_attestation_status`
+pub const STATUS_NONE: u8 = 0;
+pub const STATUS_ACTIVE: u8 = 1;
+pub const STATUS_REVOKED: u8 = 2;
+
+/// Revocation reason codes; zero is reserved for "not revoked"
+pub const REASON_STAGED: u8 = 1;
+pub const REASON_LEAKED: u8 = 2;
+pub const REASON_UPLOADED_BY_MISTAKE: u8 = 3;
+pub const REASON_LEGAL_TAKEDOWN: u8 = 4;
+pub const REASON_OTHER: u8 = 5;
+
+/// Most hooks `set_hook` accepts
+pub const MAX_HOOKS: usize = 8;
+
 #[storage]
 #[entrypoint]
 pub struct Verifier {
@@ -34,42 +62,287 @@ pub struct Verifier {
     
     // Total photos verified
     photo_count: StorageU256,
+    
+    // Set once by the constructor or `init`, never cleared
+    initialized: StorageBool,
+    
+    // Nominee of a pending two-step ownership transfer
+    pending_owner: StorageAddress,
+    
+    // Emergency stop for user-facing writes
+    paused: StorageBool,
+    
+    // Delegated roles: role => account => granted
+    roles: StorageMap<B256, StorageMap<Address, StorageBool>>,
+    
+    // Restrict verification to holders of the submitter role
+    permissioned: StorageBool,
+    
+    // Set by the constructor in the implementation's own storage, never behind a proxy
+    is_implementation: StorageBool,
+    
+    // Storage layout version reached by `migrate`
+    storage_version: StorageU64,
+    
+    // Contracts told about every mint, transfer and revocation
+    hooks: StorageVec<StorageAddress>,
 }
 
 #[public]
 impl Verifier {
-    /// Initialize the contract with the deployer as owner
-    pub fn init(&mut self) -> Result<(), Vec<u8>> {
-        self.owner.set(self.vm().msg_sender());
-        self.photo_count.set(U256::ZERO);
+    /// Bind the contract to its owner at deployment
+    /// The owner is passed explicitly since `msg_sender` is the deployer proxy
+    /// Deployed as a UUPS implementation, proxies initialize through `init`
+    #[constructor]
+    pub fn constructor(&mut self, initial_owner: Address) -> Result<(), VerifierError> {
+        self.is_implementation.set(true);
+        self.initialize(initial_owner)
+    }
+
+    /// Initialize the contract with the caller as owner
+    /// Only for deployments activated without a constructor; callable once
+    pub fn init(&mut self) -> Result<(), VerifierError> {
+        let sender = self.vm().msg_sender();
+        self.initialize(sender)
+    }
+
+    /// Nominate a new contract owner; takes effect once they accept
+    /// Nominating the zero address cancels a pending transfer
+    pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), VerifierError> {
+        let owner = self.only_owner()?;
+        self.pending_owner.set(new_owner);
+
+        emit(self.vm(), OwnershipTransferStarted { previous_owner: owner, new_owner });
+        Ok(())
+    }
+
+    /// Accept a pending ownership transfer
+    pub fn accept_ownership(&mut self) -> Result<(), VerifierError> {
+        let sender = self.vm().msg_sender();
+        if self.pending_owner.get() != sender {
+            return Err(VerifierError::NotPendingOwner(NotPendingOwner { caller: sender }));
+        }
+        let previous_owner = self.owner.get();
+        self.owner.set(sender);
+        self.pending_owner.set(Address::ZERO);
+
+        emit(self.vm(), OwnershipTransferred { previous_owner, new_owner: sender });
+        Ok(())
+    }
+
+    /// Give up contract ownership for good, leaving admin functions unusable
+    pub fn renounce_ownership(&mut self) -> Result<(), VerifierError> {
+        let owner = self.only_owner()?;
+        self.owner.set(Address::ZERO);
+        self.pending_owner.set(Address::ZERO);
+        // Pre-constructor deployments never set the flag; keep `init` closed
+        self.initialized.set(true);
+
+        emit(self.vm(), OwnershipTransferred { previous_owner: owner, new_owner: Address::ZERO });
+        Ok(())
+    }
+
+    /// Grant a role; each role is managed by its admin role (see `get_role_admin`)
+    pub fn grant_role(&mut self, role: B256, account: Address) -> Result<(), VerifierError> {
+        let sender = self.only_role(self.role_admin(role)?)?;
+        let mut granted = self.roles.setter(role);
+        let mut entry = granted.setter(account);
+        if !entry.get() {
+            entry.set(true);
+            emit(self.vm(), RoleGranted { role, account, sender });
+        }
+        Ok(())
+    }
+
+    /// Revoke a role from an account
+    pub fn revoke_role(&mut self, role: B256, account: Address) -> Result<(), VerifierError> {
+        let sender = self.only_role(self.role_admin(role)?)?;
+        self.remove_role(role, account, sender);
+        Ok(())
+    }
+
+    /// Give up a role held by the caller
+    pub fn renounce_role(&mut self, role: B256) -> Result<(), VerifierError> {
+        let sender = self.vm().msg_sender();
+        self.remove_role(role, sender, sender);
+        Ok(())
+    }
+
+    /// Restrict verification to submitters, for private enterprise deployments
+    pub fn set_permissioned_mode(&mut self, enabled: bool) -> Result<(), VerifierError> {
+        let sender = self.only_role(ADMIN_ROLE)?;
+        self.permissioned.set(enabled);
+
+        emit(self.vm(), PermissionedModeSet { enabled, sender });
+        Ok(())
+    }
+
+    /// Stop all user-facing writes during an incident
+    /// Reads and admin functions keep working while paused
+    pub fn pause(&mut self) -> Result<(), VerifierError> {
+        let account = self.only_role(PAUSER_ROLE)?;
+        if self.paused.get() {
+            return Err(VerifierError::EnforcedPause(EnforcedPause {}));
+        }
+        self.paused.set(true);
+
+        emit(self.vm(), Paused { account });
+        Ok(())
+    }
+
+    /// Resume user-facing writes
+    pub fn unpause(&mut self) -> Result<(), VerifierError> {
+        let account = self.only_role(PAUSER_ROLE)?;
+        if !self.paused.get() {
+            return Err(VerifierError::ExpectedPause(ExpectedPause {}));
+        }
+        self.paused.set(false);
+
+        emit(self.vm(), Unpaused { account });
+        Ok(())
+    }
+
+    /// Add or remove a contract told about every mint, transfer and revocation
+    /// Each hook gets `onPhotoMoved`; one that reverts reverts the change, so
+    /// hooks must not fail on photos they don't track
+    pub fn set_hook(&mut self, hook: Address, enabled: bool) -> Result<(), VerifierError> {
+        self.only_owner()?;
+        let count = self.hooks.len();
+        let index = (0..count).find(|&i| self.hooks.get(i) == Some(hook));
+        match (index, enabled) {
+            (None, true) => {
+                if count >= MAX_HOOKS {
+                    return Err(VerifierError::TooManyHooks(TooManyHooks { count: U256::from(count) }));
+                }
+                if self.vm().code_size(hook) == 0 {
+                    return Err(VerifierError::InvalidHook(InvalidHook { hook }));
+                }
+                self.hooks.push(hook);
+            }
+            (Some(index), false) => {
+                let last = self.hooks.pop().unwrap_or_default();
+                if let Some(mut slot) = self.hooks.setter(index) {
+                    slot.set(last);
+                }
+            }
+            _ => return Ok(()),
+        }
+
+        emit(self.vm(), HookUpdated { hook, enabled });
         Ok(())
     }
 
     /// Verify a photo - minimal on-chain storage
     /// All other metadata (IPFS CID, device info, etc.) stored off-chain
-    pub fn verify_photo(&mut self, photo_hash: U256, zk_commitment: U256) -> Result<U256, Vec<u8>> {
-        let timestamp = U256::from(self.vm().block_timestamp());
+    /// First claim wins: an already verified hash can't be re-registered
+    pub fn verify_photo(&mut self, photo_hash: U256, zk_commitment: U256) -> Result<U256, VerifierError> {
+        self.require_initialized()?;
+        self.when_not_paused()?;
+        if zk_commitment == U256::ZERO {
+            return Err(VerifierError::InvalidCommitment(InvalidCommitment { photo_hash }));
+        }
+
         let sender = self.vm().msg_sender();
-        
-        // Store attestation
-        let mut attestation = self.attestations.setter(photo_hash);
-        attestation.verified_at.set(timestamp);
-        attestation.owner.set(sender);
-        attestation.zk_commitment.set(zk_commitment);
-        
-        // Track owner's photo count
-        let count = self.owner_photo_count.get(sender);
-        self.owner_photo_count.setter(sender).set(count + U256::from(1));
-        
-        // Increment total counter
-        let total = self.photo_count.get();
-        self.photo_count.set(total + U256::from(1));
-        
-        Ok(timestamp)
+        self.require_submitter(sender)?;
+        self.record_attestation(photo_hash, sender, zk_commitment, &[])
+    }
+
+    /// Record a photo a companion verified for `owner`, with its IPFS `cid` if known
+    /// Only for holders of `COMPANION_ROLE`, which add ways to verify (capture
+    /// devices, passkeys, signed requests, ...) on top of this registry.
+    /// The companion checks its own proof; pause, permissioned mode and first
+    /// claim apply as in `verify_photo`. A zero commitment is allowed for photos
+    /// proved another way, e.g. by a proof key
+    pub fn record_photo(&mut self, owner: Address, photo_hash: U256, zk_commitment: U256, cid: Bytes) -> Result<U256, VerifierError> {
+        self.only_companion()?;
+        self.require_initialized()?;
+        self.when_not_paused()?;
+        self.require_submitter(owner)?;
+        self.record_attestation(photo_hash, owner, zk_commitment, &cid)
+    }
+
+    /// Move a photo for a companion, e.g. an ERC-721 transfer it authorized
+    /// `from` must own the active attestation; its commitment is cleared, as the
+    /// seller knows the secret, until the new owner calls `update_commitment`
+    pub fn move_photo(&mut self, photo_hash: U256, from: Address, to: Address) -> Result<(), VerifierError> {
+        self.only_companion()?;
+        self.require_initialized()?;
+        self.when_not_paused()?;
+        let owner = self.active_owner(photo_hash)?;
+        if owner != from {
+            return Err(VerifierError::NotPhotoOwner(NotPhotoOwner { photo_hash, owner }));
+        }
+        if to == Address::ZERO {
+            return Err(VerifierError::InvalidRecipient(InvalidRecipient { photo_hash, recipient: to }));
+        }
+
+        self.hand_over(photo_hash, from, to, U256::ZERO)
+    }
+
+    /// Replace the ZK commitment of a photo the caller already owns
+    /// Keeps the original verification timestamp and leaves counters untouched
+    pub fn update_commitment(&mut self, photo_hash: U256, zk_commitment: U256) -> Result<(), VerifierError> {
+        self.require_initialized()?;
+        self.when_not_paused()?;
+        if zk_commitment == U256::ZERO {
+            return Err(VerifierError::InvalidCommitment(InvalidCommitment { photo_hash }));
+        }
+
+        let owner = self.only_photo_owner(photo_hash)?;
+        self.attestations.setter(photo_hash).zk_commitment.set(zk_commitment);
+
+        emit(self.vm(), CommitmentUpdated { photo_hash, owner, zk_commitment });
+        Ok(())
+    }
+
+    /// Hand a photo attestation to a new owner, keeping the original `verified_at`
+    /// `zk_commitment` replaces the previous owner's commitment, whose secret the
+    /// seller still knows; zero clears it until the new owner calls `update_commitment`.
+    /// Hooks hear of it, so companions drop the previous owner's proof key and passkey
+    pub fn transfer_photo(&mut self, photo_hash: U256, new_owner: Address, zk_commitment: U256) -> Result<(), VerifierError> {
+        self.require_initialized()?;
+        self.when_not_paused()?;
+        let owner = self.only_photo_owner(photo_hash)?;
+        if new_owner == Address::ZERO || new_owner == owner {
+            return Err(VerifierError::InvalidRecipient(InvalidRecipient { photo_hash, recipient: new_owner }));
+        }
+
+        self.hand_over(photo_hash, owner, new_owner, zk_commitment)
+    }
+
+    /// Withdraw an attestation the caller owns, e.g. for a staged or leaked photo
+    /// The hash stays claimed so it can't be re-verified by anyone else
+    pub fn revoke_attestation(&mut self, photo_hash: U256, reason: u8) -> Result<(), VerifierError> {
+        self.require_initialized()?;
+        self.when_not_paused()?;
+        let owner = self.only_photo_owner(photo_hash)?;
+        self.revoke(photo_hash, owner, reason)
+    }
+
+    /// Withdraw any active attestation as a moderator
+    pub fn admin_revoke_attestation(&mut self, photo_hash: U256, reason: u8) -> Result<(), VerifierError> {
+        let admin = self.only_role(MODERATOR_ROLE)?;
+        self.active_owner(photo_hash)?;
+        self.revoke(photo_hash, admin, reason)
+    }
+
+    /// Get attestation status: `(status, revoked_at, reason)`
+    /// Status is 0 for unknown hashes, 1 while active and 2 once revoked
+    pub fn get_attestation_status(&self, photo_hash: U256) -> Result<(u8, U256, u8), VerifierError> {
+        let attestation = self.attestations.getter(photo_hash);
+        let revoked_at = attestation.revoked_at.get();
+        let status = if attestation.verified_at.get() == U256::ZERO {
+            STATUS_NONE
+        } else if revoked_at > U256::ZERO {
+            STATUS_REVOKED
+        } else {
+            STATUS_ACTIVE
+        };
+        Ok((status, revoked_at, attestation.revocation_reason.get().saturating_to::<u8>()))
     }
 
     /// Get attestation for a photo
-    pub fn get_attestation(&self, photo_hash: U256) -> Result<(U256, Address, U256), Vec<u8>> {
+    pub fn get_attestation(&self, photo_hash: U256) -> Result<(U256, Address, U256), VerifierError> {
         let attestation = self.attestations.getter(photo_hash);
         Ok((
             attestation.verified_at.get(),
@@ -78,10 +351,13 @@ impl Verifier {
         ))
     }
 
-    /// Verify ZK proof of ownership
-    pub fn verify_zk_proof(&self, photo_hash: U256, secret: U256) -> Result<bool, Vec<u8>> {
+    /// Verify ZK proof of ownership; revoked photos never pass
+    pub fn verify_zk_proof(&self, photo_hash: U256, secret: U256) -> Result<bool, VerifierError> {
         let attestation = self.attestations.getter(photo_hash);
         let stored_commitment = attestation.zk_commitment.get();
+        if stored_commitment == U256::ZERO || attestation.revoked_at.get() > U256::ZERO {
+            return Ok(false);
+        }
         
         // Compute commitment from secret using keccak256(photoHash || secret)
         let computed = self.compute_commitment(photo_hash, secret);
@@ -102,32 +378,618 @@ impl Verifier {
         data[32..64].copy_from_slice(&secret_bytes);
         
         // Keccak256 hash
-        let hash = keccak(&data);
+        let hash = keccak(data);
         U256::from_be_bytes(hash.0)
     }
 
-    /// Check if a photo is verified
-    pub fn is_verified(&self, photo_hash: U256) -> Result<bool, Vec<u8>> {
-        Ok(self.attestations.getter(photo_hash).verified_at.get() > U256::ZERO)
+    /// Check if a photo is verified and not revoked
+    pub fn is_verified(&self, photo_hash: U256) -> Result<bool, VerifierError> {
+        let attestation = self.attestations.getter(photo_hash);
+        Ok(attestation.verified_at.get() > U256::ZERO && attestation.revoked_at.get() == U256::ZERO)
     }
 
     /// Get photo owner
-    pub fn get_owner_of(&self, photo_hash: U256) -> Result<Address, Vec<u8>> {
+    pub fn get_owner_of(&self, photo_hash: U256) -> Result<Address, VerifierError> {
         Ok(self.attestations.getter(photo_hash).owner.get())
     }
 
-    /// Get owner's photo count
-    pub fn get_owner_photo_count(&self, owner: Address) -> Result<U256, Vec<u8>> {
+    /// Get owner's photo count; revoked photos drop out
+    pub fn get_owner_photo_count(&self, owner: Address) -> Result<U256, VerifierError> {
         Ok(self.owner_photo_count.get(owner))
     }
 
     /// Get total photos verified
-    pub fn get_photo_count(&self) -> Result<U256, Vec<u8>> {
+    pub fn get_photo_count(&self) -> Result<U256, VerifierError> {
         Ok(self.photo_count.get())
     }
 
+    /// Check whether an account holds a role; the contract owner holds them all
+    pub fn has_role(&self, role: B256, account: Address) -> Result<bool, VerifierError> {
+        Ok(self.holds_role(role, account))
+    }
+
+    /// Get the role that grants and revokes `role`
+    pub fn get_role_admin(&self, role: B256) -> Result<B256, VerifierError> {
+        self.role_admin(role)
+    }
+
+    /// Check whether verification is restricted to submitters
+    pub fn is_permissioned_mode(&self) -> Result<bool, VerifierError> {
+        Ok(self.permissioned.get())
+    }
+
+    /// Check whether user-facing writes are paused
+    pub fn is_paused(&self) -> Result<bool, VerifierError> {
+        Ok(self.paused.get())
+    }
+
     /// Get contract owner
-    pub fn get_contract_owner(&self) -> Result<Address, Vec<u8>> {
+    pub fn get_contract_owner(&self) -> Result<Address, VerifierError> {
         Ok(self.owner.get())
     }
-}
\ No newline at end of file
+
+    /// Get the nominee of a pending ownership transfer
+    pub fn get_pending_owner(&self) -> Result<Address, VerifierError> {
+        Ok(self.pending_owner.get())
+    }
+
+    /// Get the number of hooks
+    pub fn get_hook_count(&self) -> Result<U256, VerifierError> {
+        Ok(U256::from(self.hooks.len()))
+    }
+
+    /// Get the hook at `index`; zero past the end
+    pub fn get_hook(&self, index: U256) -> Result<Address, VerifierError> {
+        Ok(self.hooks.get(index).unwrap_or_default())
+    }
+
+    /// Move the proxy to a new implementation, then run `data` on it (e.g. `migrate()`)
+    /// Only callable through an ERC-1967 proxy, by the contract owner
+    pub fn upgrade_to_and_call(&mut self, new_implementation: Address, data: Bytes) -> Result<(), VerifierError> {
+        self.only_owner()?;
+        self.only_proxy()?;
+        if !upgrade::is_proxiable(self.vm(), new_implementation) {
+            return Err(VerifierError::InvalidImplementation(InvalidImplementation { implementation: new_implementation }));
+        }
+
+        upgrade::set_implementation(self.vm(), new_implementation);
+        emit(self.vm(), Upgraded { implementation: new_implementation });
+
+        if !data.is_empty() && !upgrade::delegate(self.vm(), new_implementation, &data) {
+            return Err(VerifierError::UpgradeCallFailed(UpgradeCallFailed { implementation: new_implementation }));
+        }
+        Ok(())
+    }
+
+    /// ERC-1822 marker: the storage slot this implementation upgrades through
+    /// Reverts behind a proxy so a proxy can't be set as an implementation
+    #[selector(name = "proxiableUUID")]
+    pub fn proxiable_uuid(&self) -> Result<B256, VerifierError> {
+        if !self.is_implementation.get() {
+            return Err(VerifierError::UnauthorizedCallContext(UnauthorizedCallContext {}));
+        }
+        Ok(upgrade::IMPLEMENTATION_SLOT)
+    }
+
+    /// Bring storage up to this implementation's `STORAGE_VERSION`
+    /// Each step runs once; call after an upgrade that bumps the version
+    pub fn migrate(&mut self) -> Result<(), VerifierError> {
+        self.only_owner()?;
+        let from = self.storage_version.get().saturating_to::<u64>();
+        if from >= upgrade::STORAGE_VERSION {
+            return Err(VerifierError::AlreadyMigrated(AlreadyMigrated { version: from }));
+        }
+
+        for version in from..upgrade::STORAGE_VERSION {
+            self.migrate_from(version);
+        }
+        self.storage_version.set(U64::from(upgrade::STORAGE_VERSION));
+
+        emit(self.vm(), StorageMigrated { from_version: from, to_version: upgrade::STORAGE_VERSION });
+        Ok(())
+    }
+
+    /// Get the implementation behind the proxy; zero when not proxied
+    pub fn get_implementation(&self) -> Result<Address, VerifierError> {
+        Ok(upgrade::implementation(self.vm()))
+    }
+
+    /// Get the storage layout version
+    pub fn get_storage_version(&self) -> Result<u64, VerifierError> {
+        Ok(self.storage_version.get().saturating_to::<u64>())
+    }
+}
+
+impl Verifier {
+    /// Store a first-claim attestation and update counters
+    /// Reverts if the hash is already attested by anyone
+    fn record_attestation(&mut self, photo_hash: U256, owner: Address, zk_commitment: U256, cid: &[u8]) -> Result<U256, VerifierError> {
+        let timestamp = U256::from(self.vm().block_timestamp());
+        self.store_attestation(photo_hash, owner, zk_commitment, timestamp, cid)?;
+        self.add_photos(owner, U256::from(1));
+        Ok(timestamp)
+    }
+
+    /// Store a first-claim attestation without touching counters
+    fn store_attestation(
+        &mut self,
+        photo_hash: U256,
+        owner: Address,
+        zk_commitment: U256,
+        timestamp: U256,
+        cid: &[u8],
+    ) -> Result<(), VerifierError> {
+        // Refuse to overwrite an existing attestation
+        let existing = self.attestations.getter(photo_hash);
+        if existing.verified_at.get() > U256::ZERO {
+            let current = existing.owner.get();
+            if current == owner {
+                return Err(VerifierError::AlreadyOwnedBySender(AlreadyOwnedBySender { photo_hash }));
+            }
+            return Err(VerifierError::AlreadyVerified(AlreadyVerified { photo_hash, owner: current }));
+        }
+        
+        // Store attestation
+        let mut attestation = self.attestations.setter(photo_hash);
+        attestation.verified_at.set(timestamp);
+        attestation.owner.set(owner);
+        attestation.zk_commitment.set(zk_commitment);
+        
+        emit(self.vm(), PhotoVerified { photo_hash, owner, zk_commitment, timestamp });
+        self.notify_hooks(photo_hash, Address::ZERO, owner, zk_commitment, cid)
+    }
+
+    /// Credit newly verified photos to the owner and the global total
+    fn add_photos(&mut self, owner: Address, amount: U256) {
+        // Track owner's photo count
+        let count = self.owner_photo_count.get(owner);
+        self.owner_photo_count.setter(owner).set(count + amount);
+        
+        // Increment total counter
+        let total = self.photo_count.get();
+        self.photo_count.set(total + amount);
+    }
+
+    /// Hand an attestation to `to` and tell the hooks
+    /// `zk_commitment` replaces the previous owner's commitment (zero clears it)
+    fn hand_over(&mut self, photo_hash: U256, from: Address, to: Address, zk_commitment: U256) -> Result<(), VerifierError> {
+        self.attestations.setter(photo_hash).zk_commitment.set(zk_commitment);
+        self.reassign(photo_hash, from, to);
+
+        emit(self.vm(), PhotoTransferred { photo_hash, from, to, zk_commitment });
+        self.notify_hooks(photo_hash, from, to, zk_commitment, &[])
+    }
+
+    /// Reassign an attestation and move it between the owners' counters
+    fn reassign(&mut self, photo_hash: U256, from: Address, to: Address) {
+        self.attestations.setter(photo_hash).owner.set(to);
+
+        let from_count = self.owner_photo_count.get(from);
+        self.owner_photo_count.setter(from).set(from_count - U256::from(1));
+        let to_count = self.owner_photo_count.get(to);
+        self.owner_photo_count.setter(to).set(to_count + U256::from(1));
+    }
+
+    /// Mark an attestation revoked with a validated reason code
+    fn revoke(&mut self, photo_hash: U256, revoked_by: Address, reason: u8) -> Result<(), VerifierError> {
+        if reason == 0 || reason > REASON_OTHER {
+            return Err(VerifierError::InvalidReasonCode(InvalidReasonCode { reason }));
+        }
+        let timestamp = U256::from(self.vm().block_timestamp());
+        let mut attestation = self.attestations.setter(photo_hash);
+        attestation.revoked_at.set(timestamp);
+        attestation.revocation_reason.set(U8::from(reason));
+        let owner = attestation.owner.get();
+        let count = self.owner_photo_count.get(owner);
+        self.owner_photo_count.setter(owner).set(count - U256::from(1));
+
+        emit(self.vm(), AttestationRevoked { photo_hash, revoked_by, reason, timestamp });
+        self.notify_hooks(photo_hash, owner, Address::ZERO, U256::ZERO, &[])
+    }
+
+    /// Tell every hook but the calling companion that a photo was minted,
+    /// moved or burned; `from` is zero on verification and `to` on revocation
+    /// The calling companion already knows, and couldn't be called back anyway
+    fn notify_hooks(&mut self, photo_hash: U256, from: Address, to: Address, zk_commitment: U256, cid: &[u8]) -> Result<(), VerifierError> {
+        let caller = self.vm().msg_sender();
+        for i in 0..self.hooks.len() {
+            let Some(hook) = self.hooks.get(i) else { continue };
+            if hook == caller {
+                continue;
+            }
+            let config = Call::new_mutating(self);
+            IPhotoHook::new(hook)
+                .on_photo_moved(self.vm(), config, photo_hash, from, to, zk_commitment, cid.to_vec().into())
+                .map_err(|_| VerifierError::HookFailed(HookFailed { hook, photo_hash }))?;
+        }
+        Ok(())
+    }
+
+    /// Owner of an active attestation
+    fn active_owner(&self, photo_hash: U256) -> Result<Address, VerifierError> {
+        let attestation = self.attestations.getter(photo_hash);
+        if attestation.verified_at.get() == U256::ZERO {
+            return Err(VerifierError::PhotoNotVerified(PhotoNotVerified { photo_hash }));
+        }
+        if attestation.revoked_at.get() > U256::ZERO {
+            return Err(VerifierError::AttestationIsRevoked(AttestationIsRevoked { photo_hash }));
+        }
+        Ok(attestation.owner.get())
+    }
+
+    /// Revert unless the caller owns the active attestation; returns the owner
+    fn only_photo_owner(&self, photo_hash: U256) -> Result<Address, VerifierError> {
+        let owner = self.active_owner(photo_hash)?;
+        if owner != self.vm().msg_sender() {
+            return Err(VerifierError::NotPhotoOwner(NotPhotoOwner { photo_hash, owner }));
+        }
+        Ok(owner)
+    }
+
+    /// Set the first contract owner; shared by the constructor and `init`
+    fn initialize(&mut self, owner: Address) -> Result<(), VerifierError> {
+        if self.initialized.get() || self.owner.get() != Address::ZERO {
+            return Err(VerifierError::AlreadyInitialized(AlreadyInitialized {}));
+        }
+        if owner == Address::ZERO {
+            return Err(VerifierError::InvalidOwner(InvalidOwner { owner }));
+        }
+        self.initialized.set(true);
+        self.owner.set(owner);
+        self.storage_version.set(U64::from(upgrade::STORAGE_VERSION));
+
+        emit(self.vm(), OwnershipTransferred { previous_owner: Address::ZERO, new_owner: owner });
+        emit(self.vm(), ContractInitialized { owner });
+        Ok(())
+    }
+
+    /// Migrate storage from `version` to `version + 1`
+    fn migrate_from(&mut self, _version: u64) {
+        // Version 1 is the first versioned layout and reads every earlier field
+        // in place; later layout changes add a `match` arm per version here
+    }
+
+    /// Revert unless running behind a proxy
+    fn only_proxy(&self) -> Result<(), VerifierError> {
+        if self.is_implementation.get() || upgrade::implementation(self.vm()) == Address::ZERO {
+            return Err(VerifierError::UnauthorizedCallContext(UnauthorizedCallContext {}));
+        }
+        Ok(())
+    }
+
+    /// Revert unless the contract has been initialized
+    fn require_initialized(&self) -> Result<(), VerifierError> {
+        if !self.initialized.get() && self.owner.get() == Address::ZERO {
+            return Err(VerifierError::NotInitialized(NotInitialized {}));
+        }
+        Ok(())
+    }
+
+    /// Check a role, treating the contract owner as holding every role
+    fn holds_role(&self, role: B256, account: Address) -> bool {
+        let owner = self.owner.get();
+        (owner != Address::ZERO && account == owner) || self.roles.getter(role).get(account)
+    }
+
+    /// Revert unless the caller holds `role`; returns the caller
+    fn only_role(&self, role: B256) -> Result<Address, VerifierError> {
+        let sender = self.vm().msg_sender();
+        if !self.holds_role(role, sender) {
+            return Err(VerifierError::MissingRole(MissingRole { role, account: sender }));
+        }
+        Ok(sender)
+    }
+
+    /// Look up the admin role of a known role
+    fn role_admin(&self, role: B256) -> Result<B256, VerifierError> {
+        roles::admin_of(role).ok_or(VerifierError::UnknownRole(UnknownRole { role }))
+    }
+
+    /// Clear an explicit role grant
+    fn remove_role(&mut self, role: B256, account: Address, sender: Address) {
+        let mut granted = self.roles.setter(role);
+        let mut entry = granted.setter(account);
+        if entry.get() {
+            entry.set(false);
+            emit(self.vm(), RoleRevoked { role, account, sender });
+        }
+    }
+
+    /// Revert unless the caller was granted `COMPANION_ROLE`
+    /// The owner's implicit roles don't count: only contracts act for other accounts
+    fn only_companion(&self) -> Result<(), VerifierError> {
+        let sender = self.vm().msg_sender();
+        if !self.roles.getter(COMPANION_ROLE).get(sender) {
+            return Err(VerifierError::MissingRole(MissingRole { role: COMPANION_ROLE, account: sender }));
+        }
+        Ok(())
+    }
+
+    /// Revert in permissioned mode unless `account` is a submitter
+    fn require_submitter(&self, account: Address) -> Result<(), VerifierError> {
+        if self.permissioned.get() && !self.holds_role(SUBMITTER_ROLE, account) {
+            return Err(VerifierError::MissingRole(MissingRole { role: SUBMITTER_ROLE, account }));
+        }
+        Ok(())
+    }
+
+    /// Revert while the contract is paused
+    fn when_not_paused(&self) -> Result<(), VerifierError> {
+        if self.paused.get() {
+            return Err(VerifierError::EnforcedPause(EnforcedPause {}));
+        }
+        Ok(())
+    }
+
+    /// Revert unless the caller is the contract owner; returns the owner
+    fn only_owner(&self) -> Result<Address, VerifierError> {
+        let sender = self.vm().msg_sender();
+        let owner = self.owner.get();
+        if owner == Address::ZERO || sender != owner {
+            return Err(VerifierError::NotOwner(NotOwner { caller: sender }));
+        }
+        Ok(owner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_sol_types::SolEvent;
+    use stylus_sdk::testing::*;
+
+    const OWNER: Address = Address::repeat_byte(1);
+    const ALICE: Address = Address::repeat_byte(2);
+    const BOB: Address = Address::repeat_byte(3);
+    const CAROL: Address = Address::repeat_byte(4);
+    const PHOTO: U256 = U256::from_limbs([0xabc, 0, 0, 0]);
+    const COMMITMENT: U256 = U256::from_limbs([0xc0, 0, 0, 0]);
+
+    /// Verifier deployed for `OWNER` at block timestamp 1000
+    fn deploy() -> (TestVM, Verifier) {
+        let vm = TestVM::default();
+        vm.set_block_timestamp(1000);
+        vm.set_sender(OWNER);
+        let mut contract = Verifier::from(&vm);
+        assert!(contract.constructor(OWNER).is_ok());
+        (vm, contract)
+    }
+
+    /// `PHOTO` verified by `ALICE`
+    fn deploy_with_photo() -> (TestVM, Verifier) {
+        let (vm, mut contract) = deploy();
+        vm.set_sender(ALICE);
+        assert!(contract.verify_photo(PHOTO, COMMITMENT).is_ok());
+        (vm, contract)
+    }
+
+    fn photo_count(contract: &Verifier, owner: Address) -> U256 {
+        contract.get_owner_photo_count(owner).unwrap_or_default()
+    }
+
+    fn logged<E: SolEvent>(vm: &TestVM) -> usize {
+        vm.get_emitted_logs().iter().filter(|(topics, _)| topics[0] == E::SIGNATURE_HASH).count()
+    }
+
+    #[test]
+    fn first_claim_wins() {
+        let (vm, mut contract) = deploy_with_photo();
+
+        assert!(matches!(
+            contract.verify_photo(PHOTO, COMMITMENT),
+            Err(VerifierError::AlreadyOwnedBySender(AlreadyOwnedBySender { photo_hash: PHOTO }))
+        ));
+        vm.set_sender(BOB);
+        assert!(matches!(
+            contract.verify_photo(PHOTO, U256::from(1)),
+            Err(VerifierError::AlreadyVerified(AlreadyVerified { owner: ALICE, .. }))
+        ));
+
+        assert_eq!(contract.get_attestation(PHOTO).ok(), Some((U256::from(1000), ALICE, COMMITMENT)));
+        assert_eq!(contract.get_photo_count().ok(), Some(U256::from(1)));
+        assert_eq!(photo_count(&contract, ALICE), U256::from(1));
+        assert_eq!(photo_count(&contract, BOB), U256::ZERO);
+        assert_eq!(logged::<PhotoVerified>(&vm), 1);
+    }
+
+    #[test]
+    fn verification_needs_a_commitment() {
+        let (vm, mut contract) = deploy();
+        vm.set_sender(ALICE);
+
+        assert!(matches!(
+            contract.verify_photo(PHOTO, U256::ZERO),
+            Err(VerifierError::InvalidCommitment(InvalidCommitment { photo_hash: PHOTO }))
+        ));
+        assert_eq!(contract.get_photo_count().ok(), Some(U256::ZERO));
+    }
+
+    #[test]
+    fn verification_needs_initialization() {
+        let vm = TestVM::default();
+        vm.set_sender(ALICE);
+        let mut contract = Verifier::from(&vm);
+
+        assert!(matches!(contract.verify_photo(PHOTO, COMMITMENT), Err(VerifierError::NotInitialized(_))));
+        assert!(contract.init().is_ok());
+        assert!(matches!(contract.init(), Err(VerifierError::AlreadyInitialized(_))));
+        assert_eq!(contract.get_contract_owner().ok(), Some(ALICE));
+    }
+
+    #[test]
+    fn transfer_moves_counters_and_keeps_verified_at() {
+        let (vm, mut contract) = deploy_with_photo();
+        vm.set_block_timestamp(2000);
+
+        assert!(contract.transfer_photo(PHOTO, BOB, U256::ZERO).is_ok());
+
+        assert_eq!(contract.get_attestation(PHOTO).ok(), Some((U256::from(1000), BOB, U256::ZERO)));
+        assert_eq!(photo_count(&contract, ALICE), U256::ZERO);
+        assert_eq!(photo_count(&contract, BOB), U256::from(1));
+        assert_eq!(contract.get_photo_count().ok(), Some(U256::from(1)));
+        assert_eq!(contract.verify_zk_proof(PHOTO, U256::ZERO).ok(), Some(false));
+        assert_eq!(logged::<PhotoTransferred>(&vm), 1);
+    }
+
+    #[test]
+    fn transfer_needs_the_owner_and_a_new_recipient() {
+        let (vm, mut contract) = deploy_with_photo();
+
+        for recipient in [Address::ZERO, ALICE] {
+            assert!(matches!(
+                contract.transfer_photo(PHOTO, recipient, COMMITMENT),
+                Err(VerifierError::InvalidRecipient(_))
+            ));
+        }
+        vm.set_sender(BOB);
+        assert!(matches!(
+            contract.transfer_photo(PHOTO, BOB, COMMITMENT),
+            Err(VerifierError::NotPhotoOwner(NotPhotoOwner { owner: ALICE, .. }))
+        ));
+        assert_eq!(photo_count(&contract, ALICE), U256::from(1));
+    }
+
+    #[test]
+    fn revocation_drops_the_photo_but_keeps_the_claim() {
+        let (vm, mut contract) = deploy_with_photo();
+        vm.set_block_timestamp(3000);
+
+        assert!(matches!(contract.revoke_attestation(PHOTO, 0), Err(VerifierError::InvalidReasonCode(_))));
+        assert!(matches!(
+            contract.revoke_attestation(PHOTO, REASON_OTHER + 1),
+            Err(VerifierError::InvalidReasonCode(_))
+        ));
+        assert!(contract.revoke_attestation(PHOTO, REASON_LEAKED).is_ok());
+
+        assert_eq!(contract.is_verified(PHOTO).ok(), Some(false));
+        assert_eq!(
+            contract.get_attestation_status(PHOTO).ok(),
+            Some((STATUS_REVOKED, U256::from(3000), REASON_LEAKED))
+        );
+        assert_eq!(photo_count(&contract, ALICE), U256::ZERO);
+        assert_eq!(contract.get_photo_count().ok(), Some(U256::from(1)));
+        assert!(matches!(
+            contract.revoke_attestation(PHOTO, REASON_LEAKED),
+            Err(VerifierError::AttestationIsRevoked(_))
+        ));
+        assert!(matches!(
+            contract.transfer_photo(PHOTO, BOB, COMMITMENT),
+            Err(VerifierError::AttestationIsRevoked(_))
+        ));
+        vm.set_sender(BOB);
+        assert!(matches!(contract.verify_photo(PHOTO, COMMITMENT), Err(VerifierError::AlreadyVerified(_))));
+    }
+
+    #[test]
+    fn moderators_revoke_any_photo() {
+        let (vm, mut contract) = deploy_with_photo();
+        vm.set_sender(CAROL);
+        assert!(matches!(
+            contract.admin_revoke_attestation(PHOTO, REASON_STAGED),
+            Err(VerifierError::MissingRole(MissingRole { role: MODERATOR_ROLE, account: CAROL }))
+        ));
+
+        vm.set_sender(OWNER);
+        assert!(contract.grant_role(MODERATOR_ROLE, CAROL).is_ok());
+        vm.set_sender(CAROL);
+        assert!(contract.admin_revoke_attestation(PHOTO, REASON_STAGED).is_ok());
+        assert_eq!(contract.get_attestation_status(PHOTO).ok(), Some((STATUS_REVOKED, U256::from(1000), REASON_STAGED)));
+        assert_eq!(photo_count(&contract, ALICE), U256::ZERO);
+    }
+
+    #[test]
+    fn pause_blocks_writes_but_not_reads() {
+        let (vm, mut contract) = deploy_with_photo();
+        vm.set_sender(CAROL);
+        assert!(matches!(contract.pause(), Err(VerifierError::MissingRole(_))));
+
+        vm.set_sender(OWNER);
+        assert!(contract.grant_role(PAUSER_ROLE, CAROL).is_ok());
+        vm.set_sender(CAROL);
+        assert!(contract.pause().is_ok());
+        assert!(matches!(contract.pause(), Err(VerifierError::EnforcedPause(_))));
+
+        vm.set_sender(ALICE);
+        assert!(matches!(contract.verify_photo(U256::from(1), COMMITMENT), Err(VerifierError::EnforcedPause(_))));
+        assert!(matches!(contract.update_commitment(PHOTO, U256::from(1)), Err(VerifierError::EnforcedPause(_))));
+        assert!(matches!(contract.transfer_photo(PHOTO, BOB, COMMITMENT), Err(VerifierError::EnforcedPause(_))));
+        assert!(matches!(contract.revoke_attestation(PHOTO, REASON_OTHER), Err(VerifierError::EnforcedPause(_))));
+        assert_eq!(contract.is_verified(PHOTO).ok(), Some(true));
+        assert_eq!(contract.get_attestation(PHOTO).ok(), Some((U256::from(1000), ALICE, COMMITMENT)));
+
+        vm.set_sender(CAROL);
+        assert!(contract.unpause().is_ok());
+        assert!(matches!(contract.unpause(), Err(VerifierError::ExpectedPause(_))));
+        vm.set_sender(ALICE);
+        assert!(contract.verify_photo(U256::from(1), COMMITMENT).is_ok());
+    }
+
+    #[test]
+    fn roles_are_managed_by_their_admin_role() {
+        let (vm, mut contract) = deploy();
+        vm.set_sender(CAROL);
+        assert!(matches!(
+            contract.grant_role(PAUSER_ROLE, CAROL),
+            Err(VerifierError::MissingRole(MissingRole { role: ADMIN_ROLE, .. }))
+        ));
+
+        vm.set_sender(OWNER);
+        assert!(contract.grant_role(ATTESTOR_MANAGER_ROLE, CAROL).is_ok());
+        assert!(matches!(contract.grant_role(B256::repeat_byte(9), CAROL), Err(VerifierError::UnknownRole(_))));
+        vm.set_sender(CAROL);
+        assert!(contract.grant_role(SUBMITTER_ROLE, ALICE).is_ok());
+        assert!(matches!(contract.grant_role(PAUSER_ROLE, ALICE), Err(VerifierError::MissingRole(_))));
+        assert_eq!(contract.has_role(SUBMITTER_ROLE, ALICE).ok(), Some(true));
+        assert_eq!(contract.has_role(SUBMITTER_ROLE, OWNER).ok(), Some(true));
+
+        assert!(contract.revoke_role(SUBMITTER_ROLE, ALICE).is_ok());
+        assert_eq!(contract.has_role(SUBMITTER_ROLE, ALICE).ok(), Some(false));
+        assert!(contract.renounce_role(ATTESTOR_MANAGER_ROLE).is_ok());
+        assert!(matches!(contract.grant_role(SUBMITTER_ROLE, ALICE), Err(VerifierError::MissingRole(_))));
+        assert_eq!(logged::<RoleGranted>(&vm), 2);
+        assert_eq!(logged::<RoleRevoked>(&vm), 2);
+    }
+
+    #[test]
+    fn permissioned_mode_admits_only_submitters() {
+        let (vm, mut contract) = deploy();
+        vm.set_sender(ALICE);
+        assert!(matches!(contract.set_permissioned_mode(true), Err(VerifierError::MissingRole(_))));
+
+        vm.set_sender(OWNER);
+        assert!(contract.set_permissioned_mode(true).is_ok());
+        vm.set_sender(ALICE);
+        assert!(matches!(
+            contract.verify_photo(PHOTO, COMMITMENT),
+            Err(VerifierError::MissingRole(MissingRole { role: SUBMITTER_ROLE, account: ALICE }))
+        ));
+
+        vm.set_sender(OWNER);
+        assert!(contract.grant_role(SUBMITTER_ROLE, ALICE).is_ok());
+        vm.set_sender(ALICE);
+        assert!(contract.verify_photo(PHOTO, COMMITMENT).is_ok());
+    }
+
+    #[test]
+    fn only_companions_record_and_move_photos() {
+        let (vm, mut contract) = deploy_with_photo();
+        // The owner's implicit roles don't include acting for other accounts
+        vm.set_sender(OWNER);
+        assert!(matches!(
+            contract.record_photo(BOB, U256::from(1), COMMITMENT, Bytes::default()),
+            Err(VerifierError::MissingRole(MissingRole { role: COMPANION_ROLE, account: OWNER }))
+        ));
+        assert!(matches!(contract.move_photo(PHOTO, ALICE, BOB), Err(VerifierError::MissingRole(_))));
+
+        assert!(contract.grant_role(COMPANION_ROLE, CAROL).is_ok());
+        vm.set_sender(CAROL);
+        assert!(contract.record_photo(BOB, U256::from(1), COMMITMENT, Bytes::default()).is_ok());
+        assert!(matches!(contract.move_photo(PHOTO, BOB, CAROL), Err(VerifierError::NotPhotoOwner(_))));
+        assert!(contract.move_photo(PHOTO, ALICE, BOB).is_ok());
+
+        assert_eq!(contract.get_owner_of(U256::from(1)).ok(), Some(BOB));
+        assert_eq!(contract.get_attestation(PHOTO).ok(), Some((U256::from(1000), BOB, U256::ZERO)));
+        assert_eq!(photo_count(&contract, ALICE), U256::ZERO);
+        assert_eq!(photo_count(&contract, BOB), U256::from(2));
+    }
+}
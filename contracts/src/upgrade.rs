@@ -0,0 +1,49 @@
+// Copyright 2025, ArbiPic
+// For licensing, see MIT OR Apache-2.0
+
+//! ERC-1967 implementation slot and UUPS (ERC-1822) helpers.
+//!
+//! The proxy only forwards calls; `upgrade_to_and_call` in the Verifier
+//! writes the implementation slot of the proxy's storage.
+
+use stylus_sdk::{
+    alloy_primitives::{b256, Address, B256, U256},
+    call::RawCall,
+    prelude::*,
+};
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)`
+pub const IMPLEMENTATION_SLOT: B256 =
+    b256!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+/// Layout version of the Verifier storage; bump with every migration step
+pub const STORAGE_VERSION: u64 = 1;
+
+sol_interface! {
+    interface IERC1822Proxiable {
+        function proxiableUUID() external view returns (bytes32);
+    }
+}
+
+/// Current implementation of the proxy this code runs behind
+pub fn implementation<H: Host + ?Sized>(host: &H) -> Address {
+    Address::from_word(host.storage_load_bytes32(U256::from_be_bytes(IMPLEMENTATION_SLOT.0)))
+}
+
+/// Point the proxy at a new implementation
+pub fn set_implementation<H: Host + ?Sized>(host: &H, implementation: Address) {
+    unsafe { host.storage_cache_bytes32(U256::from_be_bytes(IMPLEMENTATION_SLOT.0), implementation.into_word()) };
+    host.flush_cache(false);
+}
+
+/// Check that `implementation` is a UUPS implementation using the ERC-1967 slot
+pub fn is_proxiable<H: Host>(host: &H, implementation: Address) -> bool {
+    IERC1822Proxiable::new(implementation)
+        .proxiable_uuid(host, Call::new())
+        .is_ok_and(|uuid| uuid == IMPLEMENTATION_SLOT)
+}
+
+/// Run `data` against the new implementation in the proxy's storage context
+pub fn delegate<H: Host + ?Sized>(host: &H, implementation: Address, data: &[u8]) -> bool {
+    unsafe { RawCall::new_delegate(host).clear_storage_cache().call(implementation, data) }.is_ok()
+}
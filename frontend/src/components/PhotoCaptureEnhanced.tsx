@@ -2,12 +2,13 @@ import React, { useRef, useState, useCallback, useEffect, useMemo } from 'react'
 import Webcam from 'react-webcam'
 import { sha256 } from 'js-sha256'
 import { useAccount, useWaitForTransactionReceipt, useReadContract, useSwitchChain, useChainId } from 'wagmi'
-import { VERIFIER_ABI, getContractAddress, orbitL3 } from '../config'
-import { Hash, encodeFunctionData } from 'viem'
+import { VERIFIER_ABI, PHASH_ABI, getContractAddress, getCompanionAddress, orbitL3 } from '../config'
+import { Hash, encodeFunctionData, bytesToHex } from 'viem'
 import { arbitrumSepolia } from 'wagmi/chains'
 import { collectDeviceMetadata, hashToBigInt } from '../utils/deviceMetadata'
 import { generateZKProof, storeSecretSecurely, retrieveSecret, computeCommitment } from '../utils/zkProof'
 import { uploadToPinata } from '../utils/ipfs'
+import { getGrayscaleThumbnail } from '../utils/perceptualHash'
 import {
   generateVerificationUrl,
   generateTwitterShareUrl,
@@ -287,25 +288,33 @@ export const PhotoCaptureEnhanced: React.FC = () => {
         // Continue without IPFS - metadata still stored locally
       }
 
-      // Step 4: Submit MINIMAL data to blockchain (just hash + zkCommitment)
+      // Step 4: Submit hash, zkCommitment and a 32x32 grayscale thumbnail
+      // The PHash companion derives the perceptual hash from the thumbnail
+      // itself; without it the photo is verified on the Verifier alone
       setStep('signing')
       
-      // Only 2 arguments now - photoHash and zkCommitment
       const photoHashBigInt = hashToBigInt(photo.hash)
       const zkCommitmentBigInt = BigInt(zkProof.commitment)
+      const phashAddress = getCompanionAddress('phash')
       
       // Debug logging
-      console.log('Contract call args (simplified):', {
+      console.log('Contract call args:', {
         photoHash: photoHashBigInt.toString(),
         zkCommitment: zkCommitmentBigInt.toString()
       })
       
       // Encode the function call data manually
-      const callData = encodeFunctionData({
-        abi: VERIFIER_ABI,
-        functionName: 'verifyPhoto',
-        args: [photoHashBigInt, zkCommitmentBigInt],
-      })
+      const callData = phashAddress
+        ? encodeFunctionData({
+            abi: PHASH_ABI,
+            functionName: 'verifyPhotoWithThumbnail',
+            args: [photoHashBigInt, zkCommitmentBigInt, bytesToHex(await getGrayscaleThumbnail(photo.imageSrc))],
+          })
+        : encodeFunctionData({
+            abi: VERIFIER_ABI,
+            functionName: 'verifyPhoto',
+            args: [photoHashBigInt, zkCommitmentBigInt],
+          })
       
       console.log('Sending raw transaction via window.ethereum to bypass simulation...')
       console.log('Calldata:', callData)
@@ -324,9 +333,9 @@ export const PhotoCaptureEnhanced: React.FC = () => {
         method: 'eth_sendTransaction',
         params: [{
           from: address,
-          to: contractAddress,
+          to: phashAddress ?? contractAddress,
           data: callData,
-          gas: '0x7A120', // 500000 in hex - increased for reliability
+          gas: '0xB71B0', // 750000 in hex - covers the on-chain pHash and its index
           gasPrice: gasPrice // Use current gas price
         }],
       }) as Hash
@@ -2,9 +2,10 @@ import React, { useState, useCallback, useEffect, useMemo } from 'react'
 import { useParams } from 'react-router-dom'
 import { sha256 } from 'js-sha256'
 import { useReadContract, useChainId } from 'wagmi'
-import { VERIFIER_ABI, getContractAddress, PINATA_GATEWAY, orbitL3 } from '../config'
+import { VERIFIER_ABI, PHASH_ABI, getContractAddress, getCompanionAddress, PINATA_GATEWAY, orbitL3, ATTESTATION_STATUS, REVOCATION_REASONS } from '../config'
 import { generateVerificationId, generateContractUrl, getLocalVerification } from '../utils/verification'
 import { retrieveSecret } from '../utils/zkProof'
+import { computePerceptualHash, SIMILARITY_DISTANCE } from '../utils/perceptualHash'
 import { encodeFunctionData } from 'viem'
 import { arbitrumSepolia } from 'wagmi/chains'
 import { Header } from './Header'
@@ -23,6 +24,9 @@ export const VerifyPage: React.FC = () => {
   const [searchHash, setSearchHash] = useState<string | null>(null)
   const [uploadedImage, setUploadedImage] = useState<string | null>(null)
   const [isSearching, setIsSearching] = useState(false)
+  const [perceptualHash, setPerceptualHash] = useState<bigint | null>(null)
+  const [similarPhotos, setSimilarPhotos] = useState<bigint[]>([])
+  const [similarCursor, setSimilarCursor] = useState(0n)
   
   // ZK Proof state
   const [zkProofStatus, setZkProofStatus] = useState<'idle' | 'proving' | 'success' | 'failed'>('idle')
@@ -31,6 +35,7 @@ export const VerifyPage: React.FC = () => {
   // Network awareness
   const chainId = useChainId()
   const contractAddress = useMemo(() => getContractAddress(chainId), [chainId])
+  const phashAddress = getCompanionAddress('phash')
   const networkName = chainId === orbitL3.id ? 'Orbit L3' : 'Arbitrum Sepolia'
   const isOnL3 = chainId === orbitL3.id
 
@@ -124,6 +129,18 @@ export const VerifyPage: React.FC = () => {
     }
   })
 
+  // Read revocation status - revoked photos report isVerified = false
+  const { data: attestationStatus } = useReadContract({
+    address: contractAddress,
+    abi: VERIFIER_ABI,
+    functionName: 'getAttestationStatus',
+    args: searchHash ? [BigInt(`0x${searchHash}`)] : undefined,
+    query: {
+      enabled: !!searchHash
+    }
+  })
+  const isRevoked = attestationStatus?.[0] === ATTESTATION_STATUS.REVOKED
+
   // Read attestation timestamp
   const { data: attestation } = useReadContract({
     address: contractAddress,
@@ -146,6 +163,38 @@ export const VerifyPage: React.FC = () => {
     }
   }) as { data: [bigint, `0x${string}`, bigint] | undefined }
 
+  // Re-encoded copies miss the exact hash - look for near-duplicates by pHash,
+  // including ones whose pHash the photographer supplied
+  const { data: similarPage } = useReadContract({
+    address: phashAddress,
+    abi: PHASH_ABI,
+    functionName: 'findSimilarPhotos',
+    args: perceptualHash !== null ? [perceptualHash, SIMILARITY_DISTANCE, false, similarCursor] : undefined,
+    query: {
+      enabled: !!phashAddress && perceptualHash !== null && isVerified === false
+    }
+  })
+
+  // Each page adds to the matches found so far; pages without any are skipped
+  useEffect(() => {
+    if (!similarPage) return
+    if (similarPage[0].length === 0 && similarPage[1] !== 0n) {
+      setSimilarCursor(similarPage[1])
+      return
+    }
+    setSimilarPhotos(prev => [...prev, ...similarPage[0].filter(match => !prev.includes(match))])
+  }, [similarPage])
+
+  // Fingerprint an uploaded image; failures just disable the similarity lookup
+  const fingerprintImage = useCallback((dataUrl: string) => {
+    setPerceptualHash(null)
+    setSimilarPhotos([])
+    setSimilarCursor(0n)
+    computePerceptualHash(dataUrl)
+      .then(setPerceptualHash)
+      .catch(err => console.error('Perceptual hash failed:', err))
+  }, [])
+
   const handleImageUpload = useCallback((event: React.ChangeEvent<HTMLInputElement>) => {
     const file = event.target.files?.[0]
     if (!file) return
@@ -154,6 +203,7 @@ export const VerifyPage: React.FC = () => {
     reader.onload = (e) => {
       const dataUrl = e.target?.result as string
       setUploadedImage(dataUrl)
+      fingerprintImage(dataUrl)
       
       // Try to extract FULL hash from original image filename (e.g., "arbipic-original-abc123...xyz.jpg")
       const originalMatch = file.name.match(/arbipic-original-([a-f0-9]{64})/i)
@@ -187,7 +237,7 @@ export const VerifyPage: React.FC = () => {
       setInputHash(hash)
     }
     reader.readAsDataURL(file)
-  }, [])
+  }, [fingerprintImage])
 
   const handleHashSearch = useCallback(() => {
     if (!inputHash) return
@@ -223,6 +273,7 @@ export const VerifyPage: React.FC = () => {
           reader.onload = (e) => {
             const dataUrl = e.target?.result as string
             setUploadedImage(dataUrl)
+            fingerprintImage(dataUrl)
             const base64Data = dataUrl.split(',')[1]
             const hash = sha256(base64Data)
             setSearchHash(hash)
@@ -244,7 +295,7 @@ export const VerifyPage: React.FC = () => {
     } catch (err) {
       console.error('Failed to read clipboard:', err)
     }
-  }, [handleHashSearch])
+  }, [handleHashSearch, fingerprintImage])
 
   // Parse attestation data (now returns [timestamp, owner, zkCommitment])
   const parsedMetadata: PhotoMetadata | null = metadata ? {
@@ -489,6 +540,19 @@ export const VerifyPage: React.FC = () => {
                   </div>
                 </div>
               </>
+            ) : isRevoked && attestationStatus ? (
+              /* Revoked Screen */
+              <div className="p-16 text-center bg-amber-500/5">
+                <div className="inline-flex items-center justify-center w-20 h-20 rounded-full bg-amber-500/10 text-amber-500 mb-6 border border-amber-500/20">
+                   <svg className="w-10 h-10" fill="none" viewBox="0 0 24 24" stroke="currentColor"><path strokeLinecap="round" strokeLinejoin="round" strokeWidth={1.5} d="M12 9v4m0 4h.01M5.07 19h13.86a2 2 0 001.73-3L13.73 4a2 2 0 00-3.46 0L3.34 16a2 2 0 001.73 3z" /></svg>
+                </div>
+                <h2 className="text-2xl font-bold text-white mb-2">Attestation Revoked</h2>
+                <p className="text-zinc-400 max-w-md mx-auto">
+                  Revoked on {new Date(Number(attestationStatus[1]) * 1000).toLocaleString()}
+                  {' · '}
+                  {REVOCATION_REASONS[attestationStatus[2]] ?? 'Unknown reason'}
+                </p>
+              </div>
             ) : (
               /* Not Verified Screen */
               <div className="p-16 text-center bg-red-500/5">
@@ -499,6 +563,35 @@ export const VerifyPage: React.FC = () => {
                 <p className="text-zinc-400 max-w-md mx-auto">
                   This photo could not be found on the Arbitrum blockchain. It may be unverified or modified.
                 </p>
+                {similarPhotos.length > 0 && (
+                  <div className="mt-8 max-w-md mx-auto text-left">
+                    <p className="text-sm text-zinc-300 mb-3">
+                      Visually similar verified photos - this may be a re-encoded or resized copy:
+                    </p>
+                    <div className="space-y-2">
+                      {similarPhotos.map(match => {
+                        const matchHash = match.toString(16).padStart(64, '0')
+                        return (
+                          <button
+                            key={matchHash}
+                            onClick={() => { setSearchHash(matchHash); setInputHash(matchHash) }}
+                            className="w-full px-4 py-2 rounded-lg bg-zinc-900/50 border border-zinc-800 hover:border-zinc-600 text-xs font-mono text-zinc-400 hover:text-white truncate transition-all"
+                          >
+                            {matchHash}
+                          </button>
+                        )
+                      })}
+                    </div>
+                    {similarPage && similarPage[1] !== 0n && (
+                      <button
+                        onClick={() => setSimilarCursor(similarPage[1])}
+                        className="mt-3 text-xs text-zinc-400 hover:text-white transition-all"
+                      >
+                        Search further
+                      </button>
+                    )}
+                  </div>
+                )}
               </div>
             )}
           </div>
@@ -76,6 +76,50 @@ export const VERIFIER_ABI = [
     ],
     "stateMutability": "nonpayable"
   },
+  {
+    "type": "function",
+    "name": "updateCommitment",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "zkCommitment", "type": "uint256" }
+    ],
+    "outputs": [],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "transferPhoto",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "newOwner", "type": "address" },
+      { "name": "zkCommitment", "type": "uint256" }
+    ],
+    "outputs": [],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "revokeAttestation",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "reason", "type": "uint8" }
+    ],
+    "outputs": [],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "getAttestationStatus",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "status", "type": "uint8" },
+      { "name": "revokedAt", "type": "uint256" },
+      { "name": "reason", "type": "uint8" }
+    ],
+    "stateMutability": "view"
+  },
   {
     "type": "function",
     "name": "getAttestation",
@@ -143,6 +187,54 @@ export const VERIFIER_ABI = [
     ],
     "stateMutability": "view"
   },
+  {
+    "type": "function",
+    "name": "isPaused",
+    "inputs": [],
+    "outputs": [
+      { "name": "", "type": "bool" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "isPermissionedMode",
+    "inputs": [],
+    "outputs": [
+      { "name": "", "type": "bool" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "hasRole",
+    "inputs": [
+      { "name": "role", "type": "bytes32" },
+      { "name": "account", "type": "address" }
+    ],
+    "outputs": [
+      { "name": "", "type": "bool" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "getImplementation",
+    "inputs": [],
+    "outputs": [
+      { "name": "", "type": "address" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "getStorageVersion",
+    "inputs": [],
+    "outputs": [
+      { "name": "", "type": "uint64" }
+    ],
+    "stateMutability": "view"
+  },
   {
     "type": "function",
     "name": "getContractOwner",
@@ -151,21 +243,1061 @@ export const VERIFIER_ABI = [
       { "name": "", "type": "address" }
     ],
     "stateMutability": "view"
+  },
+  {
+    "type": "event",
+    "name": "PhotoVerified",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256", "indexed": true },
+      { "name": "owner", "type": "address", "indexed": true },
+      { "name": "zkCommitment", "type": "uint256", "indexed": false },
+      { "name": "timestamp", "type": "uint256", "indexed": false }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "event",
+    "name": "CommitmentUpdated",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256", "indexed": true },
+      { "name": "owner", "type": "address", "indexed": true },
+      { "name": "zkCommitment", "type": "uint256", "indexed": false }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "event",
+    "name": "PhotoTransferred",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256", "indexed": true },
+      { "name": "from", "type": "address", "indexed": true },
+      { "name": "to", "type": "address", "indexed": true },
+      { "name": "zkCommitment", "type": "uint256", "indexed": false }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "event",
+    "name": "AttestationRevoked",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256", "indexed": true },
+      { "name": "revokedBy", "type": "address", "indexed": true },
+      { "name": "reason", "type": "uint8", "indexed": false },
+      { "name": "timestamp", "type": "uint256", "indexed": false }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "event",
+    "name": "RoleGranted",
+    "inputs": [
+      { "name": "role", "type": "bytes32", "indexed": true },
+      { "name": "account", "type": "address", "indexed": true },
+      { "name": "sender", "type": "address", "indexed": true }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "event",
+    "name": "RoleRevoked",
+    "inputs": [
+      { "name": "role", "type": "bytes32", "indexed": true },
+      { "name": "account", "type": "address", "indexed": true },
+      { "name": "sender", "type": "address", "indexed": true }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "event",
+    "name": "ContractInitialized",
+    "inputs": [
+      { "name": "owner", "type": "address", "indexed": true }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "event",
+    "name": "OwnershipTransferred",
+    "inputs": [
+      { "name": "previousOwner", "type": "address", "indexed": true },
+      { "name": "newOwner", "type": "address", "indexed": true }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "event",
+    "name": "OwnershipTransferStarted",
+    "inputs": [
+      { "name": "previousOwner", "type": "address", "indexed": true },
+      { "name": "newOwner", "type": "address", "indexed": true }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "error",
+    "name": "AlreadyOwnedBySender",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "AlreadyVerified",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "owner", "type": "address" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "PhotoNotVerified",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "NotPhotoOwner",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "owner", "type": "address" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "NotOwner",
+    "inputs": [
+      { "name": "caller", "type": "address" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "NotInitialized",
+    "inputs": []
+  },
+  {
+    "type": "error",
+    "name": "AlreadyInitialized",
+    "inputs": []
+  },
+  {
+    "type": "error",
+    "name": "InvalidCommitment",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "NotPendingOwner",
+    "inputs": [
+      { "name": "caller", "type": "address" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "InvalidOwner",
+    "inputs": [
+      { "name": "owner", "type": "address" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "InvalidRecipient",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "recipient", "type": "address" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "AttestationIsRevoked",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "InvalidReasonCode",
+    "inputs": [
+      { "name": "reason", "type": "uint8" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "EnforcedPause",
+    "inputs": []
+  },
+  {
+    "type": "error",
+    "name": "ExpectedPause",
+    "inputs": []
+  },
+  {
+    "type": "error",
+    "name": "MissingRole",
+    "inputs": [
+      { "name": "role", "type": "bytes32" },
+      { "name": "account", "type": "address" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "UnknownRole",
+    "inputs": [
+      { "name": "role", "type": "bytes32" }
+    ]
   }
 ] as const
 
-// Update this with your deployed contract address
-export const VERIFIER_ADDRESS = '0xeb246817d2440f82f4b4c04c2c120afefe1e5ec4' as const
-
-// L3 Orbit contract address
-export const L3_VERIFIER_ADDRESS = '0x1294b86822ff4976bfe136cb06cf43ec7fcf2574' as const
-
-// Get the correct contract address based on chain ID
-export function getContractAddress(chainId: number): `0x${string}` {
-  if (chainId === 333333) {
-    return L3_VERIFIER_ADDRESS
+// Relay companion: EIP-712 signed verifications submitted by anyone
+export const RELAY_ABI = [
+  {
+    "type": "function",
+    "name": "verifyPhotoWithSig",
+    "inputs": [
+      { "name": "owner", "type": "address" },
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "zkCommitment", "type": "uint256" },
+      { "name": "deadline", "type": "uint256" },
+      { "name": "signature", "type": "bytes" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256" }
+    ],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "getNonce",
+    "inputs": [
+      { "name": "owner", "type": "address" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "error",
+    "name": "SignatureExpired",
+    "inputs": [
+      { "name": "deadline", "type": "uint256" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "InvalidSignature",
+    "inputs": []
+  },
+  {
+    "type": "error",
+    "name": "VerifierRejected",
+    "inputs": [
+      { "name": "reason", "type": "bytes" }
+    ]
   }
-  return VERIFIER_ADDRESS
+] as const
+
+// Bulk companion: verification bursts and Merkle-root batches
+export const BULK_ABI = [
+  {
+    "type": "function",
+    "name": "verifyPhotosBatch",
+    "inputs": [
+      { "name": "photoHashes", "type": "uint256[]" },
+      { "name": "zkCommitments", "type": "uint256[]" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint8[]" }
+    ],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "registerMerkleRoot",
+    "inputs": [
+      { "name": "merkleRoot", "type": "bytes32" },
+      { "name": "leafCount", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256" }
+    ],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "isVerifiedInBatch",
+    "inputs": [
+      { "name": "merkleRoot", "type": "bytes32" },
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "proof", "type": "bytes32[]" }
+    ],
+    "outputs": [
+      { "name": "", "type": "bool" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "getBatchAttestation",
+    "inputs": [
+      { "name": "merkleRoot", "type": "bytes32" },
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "proof", "type": "bytes32[]" }
+    ],
+    "outputs": [
+      { "name": "registeredAt", "type": "uint256" },
+      { "name": "owner", "type": "address" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "event",
+    "name": "MerkleRootRegistered",
+    "inputs": [
+      { "name": "merkleRoot", "type": "bytes32", "indexed": true },
+      { "name": "owner", "type": "address", "indexed": true },
+      { "name": "leafCount", "type": "uint256", "indexed": false },
+      { "name": "timestamp", "type": "uint256", "indexed": false }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "error",
+    "name": "LengthMismatch",
+    "inputs": [
+      { "name": "hashes", "type": "uint256" },
+      { "name": "commitments", "type": "uint256" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "InvalidBatchSize",
+    "inputs": [
+      { "name": "size", "type": "uint256" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "InvalidMerkleRoot",
+    "inputs": [
+      { "name": "merkleRoot", "type": "bytes32" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "MerkleRootAlreadyRegistered",
+    "inputs": [
+      { "name": "merkleRoot", "type": "bytes32" },
+      { "name": "owner", "type": "address" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "VerifierRejected",
+    "inputs": [
+      { "name": "reason", "type": "bytes" }
+    ]
+  }
+] as const
+
+// Proofs companion: signature-based ownership proofs
+export const PROOFS_ABI = [
+  {
+    "type": "function",
+    "name": "verifyPhotoWithKey",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "proofKey", "type": "address" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256" }
+    ],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "setProofKey",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "proofKey", "type": "address" }
+    ],
+    "outputs": [],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "verifySignatureProof",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "challenge", "type": "bytes32" },
+      { "name": "signature", "type": "bytes" }
+    ],
+    "outputs": [
+      { "name": "", "type": "bool" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "getProofKey",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "", "type": "address" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "event",
+    "name": "ProofKeyUpdated",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256", "indexed": true },
+      { "name": "owner", "type": "address", "indexed": true },
+      { "name": "proofKey", "type": "address", "indexed": true }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "error",
+    "name": "InvalidProofKey",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "VerifierRejected",
+    "inputs": [
+      { "name": "reason", "type": "bytes" }
+    ]
+  }
+] as const
+
+// Snark companion: Groth16 ownership proofs
+export const SNARK_ABI = [
+  {
+    "type": "function",
+    "name": "verifySnarkProof",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "challenge", "type": "bytes32" },
+      { "name": "proof", "type": "uint256[8]" }
+    ],
+    "outputs": [
+      { "name": "", "type": "bool" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "error",
+    "name": "VerifyingKeyNotSet",
+    "inputs": []
+  }
+] as const
+
+// Gallery companion: paginated listings of each owner's photos
+export const GALLERY_ABI = [
+  {
+    "type": "function",
+    "name": "getOwnerPhotos",
+    "inputs": [
+      { "name": "owner", "type": "address" },
+      { "name": "offset", "type": "uint256" },
+      { "name": "limit", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256[]" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "getPhotoId",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "getPhotoById",
+    "inputs": [
+      { "name": "id", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "getPhotos",
+    "inputs": [
+      { "name": "offset", "type": "uint256" },
+      { "name": "limit", "type": "uint256" }
+    ],
+    "outputs": [
+      {
+        "name": "",
+        "type": "tuple[]",
+        "components": [
+          { "name": "photoHash", "type": "uint256" },
+          { "name": "verifiedAt", "type": "uint256" },
+          { "name": "owner", "type": "address" },
+          { "name": "zkCommitment", "type": "uint256" },
+          { "name": "revokedAt", "type": "uint256" }
+        ]
+      }
+    ],
+    "stateMutability": "view"
+  }
+] as const
+
+// Devices companion: enrolled capture devices and WebAuthn passkeys
+export const DEVICES_ABI = [
+  {
+    "type": "function",
+    "name": "verifyPhotoFromDevice",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "zkCommitment", "type": "uint256" },
+      { "name": "device", "type": "address" },
+      { "name": "captureTime", "type": "uint256" },
+      { "name": "signature", "type": "bytes" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256" }
+    ],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "getPhotoDevice",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "device", "type": "address" },
+      { "name": "capturedAt", "type": "uint256" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "isDeviceActive",
+    "inputs": [
+      { "name": "device", "type": "address" }
+    ],
+    "outputs": [
+      { "name": "", "type": "bool" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "verifyPhotoWithPasskey",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "zkCommitment", "type": "uint256" },
+      { "name": "publicKey", "type": "uint256[2]" },
+      { "name": "authenticatorData", "type": "bytes" },
+      { "name": "clientDataJson", "type": "bytes" },
+      { "name": "signature", "type": "uint256[2]" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256" }
+    ],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "passkeyAttestationChallenge",
+    "inputs": [
+      { "name": "owner", "type": "address" },
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "zkCommitment", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "", "type": "bytes32" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "verifyPasskeyProof",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "challenge", "type": "bytes32" },
+      { "name": "authenticatorData", "type": "bytes" },
+      { "name": "clientDataJson", "type": "bytes" },
+      { "name": "signature", "type": "uint256[2]" }
+    ],
+    "outputs": [
+      { "name": "", "type": "bool" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "getPasskey",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "x", "type": "uint256" },
+      { "name": "y", "type": "uint256" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "event",
+    "name": "PasskeyUpdated",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256", "indexed": true },
+      { "name": "owner", "type": "address", "indexed": true },
+      { "name": "x", "type": "uint256", "indexed": false },
+      { "name": "y", "type": "uint256", "indexed": false }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "event",
+    "name": "PhotoCaptured",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256", "indexed": true },
+      { "name": "device", "type": "address", "indexed": true },
+      { "name": "captureTime", "type": "uint256", "indexed": false }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "error",
+    "name": "DeviceNotEnrolled",
+    "inputs": [
+      { "name": "device", "type": "address" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "DeviceIsRevoked",
+    "inputs": [
+      { "name": "device", "type": "address" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "InvalidCaptureTime",
+    "inputs": [
+      { "name": "captureTime", "type": "uint256" }
+    ]
+  },
+  {
+    "type": "error",
+    "name": "InvalidSignature",
+    "inputs": []
+  },
+  {
+    "type": "error",
+    "name": "VerifierRejected",
+    "inputs": [
+      { "name": "reason", "type": "bytes" }
+    ]
+  }
+] as const
+
+// Metadata companion: IPFS details and key-value attributes
+export const METADATA_ABI = [
+  {
+    "type": "function",
+    "name": "verifyPhotoWithDetails",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "zkCommitment", "type": "uint256" },
+      {
+        "name": "details",
+        "type": "tuple",
+        "components": [
+          { "name": "cid", "type": "bytes" },
+          { "name": "thumbnailCid", "type": "bytes" },
+          { "name": "locationHash", "type": "bytes32" },
+          { "name": "deviceFingerprint", "type": "bytes32" },
+          { "name": "mimeType", "type": "string" }
+        ]
+      }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256" }
+    ],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "getAttestationDetails",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ],
+    "outputs": [
+      {
+        "name": "",
+        "type": "tuple",
+        "components": [
+          { "name": "cid", "type": "bytes" },
+          { "name": "thumbnailCid", "type": "bytes" },
+          { "name": "locationHash", "type": "bytes32" },
+          { "name": "deviceFingerprint", "type": "bytes32" },
+          { "name": "mimeType", "type": "string" }
+        ]
+      }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "setAttribute",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "key", "type": "string" },
+      { "name": "value", "type": "bytes" }
+    ],
+    "outputs": [],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "getAttribute",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "key", "type": "string" }
+    ],
+    "outputs": [
+      { "name": "", "type": "bytes" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "getAttributeKeys",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "", "type": "string[]" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "event",
+    "name": "AttributeSet",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256", "indexed": true },
+      { "name": "key", "type": "string", "indexed": false },
+      { "name": "value", "type": "bytes", "indexed": false }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "event",
+    "name": "AttestationDetailsSet",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256", "indexed": true },
+      { "name": "cid", "type": "bytes", "indexed": false }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "error",
+    "name": "VerifierRejected",
+    "inputs": [
+      { "name": "reason", "type": "bytes" }
+    ]
+  }
+] as const
+
+// PHash companion: perceptual hashes and near-duplicate search
+export const PHASH_ABI = [
+  {
+    "type": "function",
+    "name": "verifyPhotoWithPhash",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "zkCommitment", "type": "uint256" },
+      { "name": "perceptualHash", "type": "uint64" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256" }
+    ],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "verifyPhotoWithThumbnail",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" },
+      { "name": "zkCommitment", "type": "uint256" },
+      { "name": "thumbnail", "type": "bytes" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256" }
+    ],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "function",
+    "name": "getPerceptualHash",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "isSet", "type": "bool" },
+      { "name": "perceptualHash", "type": "uint64" },
+      { "name": "computed", "type": "bool" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "findSimilarPhotos",
+    "inputs": [
+      { "name": "perceptualHash", "type": "uint64" },
+      { "name": "maxDistance", "type": "uint8" },
+      { "name": "computedOnly", "type": "bool" },
+      { "name": "cursor", "type": "uint64" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256[]" },
+      { "name": "", "type": "uint64" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "event",
+    "name": "PerceptualHashSet",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256", "indexed": true },
+      { "name": "perceptualHash", "type": "uint64", "indexed": false },
+      { "name": "computed", "type": "bool", "indexed": false }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "error",
+    "name": "VerifierRejected",
+    "inputs": [
+      { "name": "reason", "type": "bytes" }
+    ]
+  }
+] as const
+
+// Token companion: ERC-721 and ERC-2981 view of the attestations
+export const TOKEN_ABI = [
+  {
+    "type": "function",
+    "name": "balanceOf",
+    "inputs": [
+      { "name": "owner", "type": "address" }
+    ],
+    "outputs": [
+      { "name": "", "type": "uint256" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "ownerOf",
+    "inputs": [
+      { "name": "tokenId", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "", "type": "address" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "tokenURI",
+    "inputs": [
+      { "name": "tokenId", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "", "type": "string" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "royaltyInfo",
+    "inputs": [
+      { "name": "tokenId", "type": "uint256" },
+      { "name": "salePrice", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "", "type": "address" },
+      { "name": "", "type": "uint256" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "transferFrom",
+    "inputs": [
+      { "name": "from", "type": "address" },
+      { "name": "to", "type": "address" },
+      { "name": "tokenId", "type": "uint256" }
+    ],
+    "outputs": [],
+    "stateMutability": "nonpayable"
+  },
+  {
+    "type": "event",
+    "name": "Transfer",
+    "inputs": [
+      { "name": "from", "type": "address", "indexed": true },
+      { "name": "to", "type": "address", "indexed": true },
+      { "name": "tokenId", "type": "uint256", "indexed": true }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "error",
+    "name": "VerifierRejected",
+    "inputs": [
+      { "name": "reason", "type": "bytes" }
+    ]
+  }
+] as const
+
+// EAS companion: Ethereum Attestation Service mirror
+export const EAS_ABI = [
+  {
+    "type": "function",
+    "name": "getEasConfig",
+    "inputs": [],
+    "outputs": [
+      { "name": "", "type": "address" },
+      { "name": "", "type": "bytes32" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "getEasUid",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ],
+    "outputs": [
+      { "name": "", "type": "bytes32" }
+    ],
+    "stateMutability": "view"
+  },
+  {
+    "type": "event",
+    "name": "EasRevoked",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256", "indexed": true },
+      { "name": "uid", "type": "bytes32", "indexed": false }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "event",
+    "name": "EasAttested",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256", "indexed": true },
+      { "name": "uid", "type": "bytes32", "indexed": false }
+    ],
+    "anonymous": false
+  },
+  {
+    "type": "error",
+    "name": "EasAttestationFailed",
+    "inputs": [
+      { "name": "photoHash", "type": "uint256" }
+    ]
+  }
+] as const
+
+// Attestation status codes returned by getAttestationStatus
+export const ATTESTATION_STATUS = {
+  NONE: 0,
+  ACTIVE: 1,
+  REVOKED: 2,
+} as const
+
+// Per-item result codes returned by verifyPhotosBatch
+export const BATCH_RESULT = {
+  VERIFIED: 0,
+  ALREADY_OWNED: 1,
+  ALREADY_VERIFIED: 2,
+  INVALID_COMMITMENT: 3,
+} as const
+
+// Largest burst accepted by verifyPhotosBatch (Bulk companion)
+export const MAX_BATCH_SIZE = 200
+
+// Largest page returned by the Gallery companion's getOwnerPhotos and getPhotos
+export const MAX_PAGE_SIZE = 100
+
+// Revocation reason codes accepted by revokeAttestation
+export const REVOCATION_REASONS: Record<number, string> = {
+  1: 'Staged',
+  2: 'Leaked',
+  3: 'Uploaded by mistake',
+  4: 'Legal takedown',
+  5: 'Other',
+}
+
+// Role ids (keccak256 of the role name) used by hasRole / grantRole
+export const ROLES = {
+  ADMIN: '0xa49807205ce4d355092ef5a8a18f56e8913cf4a201fbe287825b095693c21775',
+  PAUSER: '0x65d7a28e3265b37a6474929f336521b332c1681b933f6cb9f3376673440d862a',
+  MODERATOR: '0x71f3d55856e4058ed06ee057d79ada615f65cdf5f9ee88181b914225088f834f',
+  ATTESTOR_MANAGER: '0xb14660b418a31c41c49910ea4e1ea1db721d1282b2cfaf9895cf50eab055bd8d',
+  DEVICE_MANAGER: '0x20c605fd0c297c12fdef1ea05fc5e693eeeda1f5ffb147a823a0db69c8bbea9e',
+  SUBMITTER: '0xe1a65d1a914580ff6931bc952f0fb26573e9282358a4458bceb9ccc6d923d041',
+  COMPANION: '0xe38cfb508fb0ea1f74eee16ff96be52d091242d49e11472293b68387d7ff69b4',
+} as const
+
+// Update this with your deployed contract address
+export const VERIFIER_ADDRESS = '0xeb246817d2440f82f4b4c04c2c120afefe1e5ec4' as const
+
+// L3 Orbit contract address
+export const L3_VERIFIER_ADDRESS = '0x1294b86822ff4976bfe136cb06cf43ec7fcf2574' as const
+
+// Get the correct contract address based on chain ID
+export function getContractAddress(chainId: number): `0x${string}` {
+  if (chainId === 333333) {
+    return L3_VERIFIER_ADDRESS
+  }
+  return VERIFIER_ADDRESS
+}
+
+// Companion contracts deployed next to the Verifier by scripts/deploy-enhanced.sh
+// Features of a companion whose address isn't set stay off
+export type Companion = 'relay' | 'bulk' | 'proofs' | 'snark' | 'gallery' | 'devices' | 'metadata' | 'phash' | 'token' | 'eas'
+
+const COMPANION_ADDRESSES: Record<Companion, string | undefined> = {
+  relay: import.meta.env.VITE_RELAY_ADDRESS,
+  bulk: import.meta.env.VITE_BULK_ADDRESS,
+  proofs: import.meta.env.VITE_PROOFS_ADDRESS,
+  snark: import.meta.env.VITE_SNARK_ADDRESS,
+  gallery: import.meta.env.VITE_GALLERY_ADDRESS,
+  devices: import.meta.env.VITE_DEVICES_ADDRESS,
+  metadata: import.meta.env.VITE_METADATA_ADDRESS,
+  phash: import.meta.env.VITE_PHASH_ADDRESS,
+  token: import.meta.env.VITE_TOKEN_ADDRESS,
+  eas: import.meta.env.VITE_EAS_ADDRESS,
+}
+
+// Get a companion's address, if it is deployed
+export function getCompanionAddress(companion: Companion): `0x${string}` | undefined {
+  const address = COMPANION_ADDRESSES[companion]
+  return address ? (address as `0x${string}`) : undefined
 }
 
 // Pinata IPFS configuration (use JWT for SDK)
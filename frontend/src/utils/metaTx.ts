@@ -0,0 +1,60 @@
+/**
+ * Gasless Verification Utilities
+ * Build EIP-712 typed data for relayer-submitted verifyPhotoWithSig calls
+ */
+
+/**
+ * EIP-712 domain - mirrors `eip712::domain` in the Stylus contract
+ */
+export function getVerifierDomain(chainId: number, contractAddress: `0x${string}`) {
+  return {
+    name: 'ArbiPic Verifier',
+    version: '1',
+    chainId,
+    verifyingContract: contractAddress,
+  } as const
+}
+
+export const VERIFY_PHOTO_TYPES = {
+  VerifyPhoto: [
+    { name: 'owner', type: 'address' },
+    { name: 'photoHash', type: 'uint256' },
+    { name: 'zkCommitment', type: 'uint256' },
+    { name: 'nonce', type: 'uint256' },
+    { name: 'deadline', type: 'uint256' },
+  ],
+} as const
+
+/**
+ * Build the typed data the photographer signs with signTypedData
+ * `nonce` comes from the contract's getNonce(owner)
+ */
+export function buildVerifyPhotoTypedData(params: {
+  chainId: number
+  contractAddress: `0x${string}`
+  owner: `0x${string}`
+  photoHash: bigint
+  zkCommitment: bigint
+  nonce: bigint
+  deadline: bigint
+}) {
+  return {
+    domain: getVerifierDomain(params.chainId, params.contractAddress),
+    types: VERIFY_PHOTO_TYPES,
+    primaryType: 'VerifyPhoto' as const,
+    message: {
+      owner: params.owner,
+      photoHash: params.photoHash,
+      zkCommitment: params.zkCommitment,
+      nonce: params.nonce,
+      deadline: params.deadline,
+    },
+  }
+}
+
+/**
+ * Deadline a given number of seconds from now (default 1 hour)
+ */
+export function signatureDeadline(secondsFromNow: number = 3600): bigint {
+  return BigInt(Math.floor(Date.now() / 1000) + secondsFromNow)
+}
@@ -3,6 +3,7 @@
  */
 
 import { sha256 } from 'js-sha256';
+import { stringToHex } from 'viem';
 
 export interface PhotoMetadata {
   // Image data
@@ -154,6 +155,24 @@ export function formatMetadata(metadata: Partial<PhotoMetadata>): Record<string,
   };
 }
 
+/**
+ * Build the on-chain extended record for verifyPhotoWithDetails
+ * CIDs are stored as UTF-8 bytes; missing hashes become zero
+ */
+export function toAttestationDetails(
+  metadata: Partial<PhotoMetadata>,
+  mimeType: string = 'image/jpeg'
+) {
+  const zero = `0x${'0'.repeat(64)}` as `0x${string}`;
+  return {
+    cid: stringToHex(metadata.ipfsCid || ''),
+    thumbnailCid: stringToHex(metadata.thumbnailCid || ''),
+    locationHash: (metadata.locationHash || zero) as `0x${string}`,
+    deviceFingerprint: (metadata.deviceFingerprint || zero) as `0x${string}`,
+    mimeType,
+  };
+}
+
 /**
  * Export metadata as JSON
  */
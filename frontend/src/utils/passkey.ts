@@ -0,0 +1,95 @@
+/**
+ * Passkey Utilities
+ * Create P-256 passkeys and produce WebAuthn assertions for
+ * verifyPhotoWithPasskey / verifyPasskeyProof
+ */
+
+import { bytesToHex, hexToBytes } from 'viem'
+
+export interface PasskeyCredential {
+  credentialId: `0x${string}`
+  publicKey: [bigint, bigint]
+}
+
+export interface PasskeyAssertion {
+  authenticatorData: `0x${string}`
+  clientDataJSON: `0x${string}`
+  signature: [bigint, bigint]
+}
+
+/**
+ * Create a new ES256 passkey and read its public key as [x, y]
+ */
+export async function createPasskey(userName: string): Promise<PasskeyCredential> {
+  const credential = (await navigator.credentials.create({
+    publicKey: {
+      challenge: crypto.getRandomValues(new Uint8Array(32)),
+      rp: { name: 'ArbiPic' },
+      user: {
+        id: crypto.getRandomValues(new Uint8Array(16)),
+        name: userName,
+        displayName: userName,
+      },
+      pubKeyCredParams: [{ type: 'public-key', alg: -7 }], // ES256 (P-256)
+      authenticatorSelection: { userVerification: 'required', residentKey: 'preferred' },
+    },
+  })) as PublicKeyCredential | null
+  if (!credential) throw new Error('Passkey creation was cancelled')
+
+  const response = credential.response as AuthenticatorAttestationResponse
+  const spki = response.getPublicKey()
+  if (!spki) throw new Error('Authenticator did not return a public key')
+
+  // SPKI for P-256 ends with the uncompressed point 0x04 || x || y
+  const point = new Uint8Array(spki).slice(-64)
+  return {
+    credentialId: bytesToHex(new Uint8Array(credential.rawId)),
+    publicKey: [
+      BigInt(bytesToHex(point.slice(0, 32))),
+      BigInt(bytesToHex(point.slice(32))),
+    ],
+  }
+}
+
+/**
+ * Sign a 32-byte contract challenge with a passkey
+ * Use the contract's passkeyAttestationChallenge or ownershipChallengeDigest
+ */
+export async function signWithPasskey(
+  credentialId: `0x${string}`,
+  challenge: `0x${string}`
+): Promise<PasskeyAssertion> {
+  const credential = (await navigator.credentials.get({
+    publicKey: {
+      challenge: hexToBytes(challenge),
+      allowCredentials: [{ type: 'public-key', id: hexToBytes(credentialId) }],
+      userVerification: 'required',
+    },
+  })) as PublicKeyCredential | null
+  if (!credential) throw new Error('Passkey signing was cancelled')
+
+  const response = credential.response as AuthenticatorAssertionResponse
+  return {
+    authenticatorData: bytesToHex(new Uint8Array(response.authenticatorData)),
+    clientDataJSON: bytesToHex(new Uint8Array(response.clientDataJSON)),
+    signature: parseDerSignature(new Uint8Array(response.signature)),
+  }
+}
+
+/**
+ * Decode an ASN.1 DER ECDSA signature into [r, s]
+ */
+export function parseDerSignature(der: Uint8Array): [bigint, bigint] {
+  if (der[0] !== 0x30) throw new Error('Invalid DER signature')
+  let offset = 2
+  const readInteger = (): bigint => {
+    if (der[offset] !== 0x02) throw new Error('Invalid DER integer')
+    const length = der[offset + 1]
+    const value = der.slice(offset + 2, offset + 2 + length)
+    offset += 2 + length
+    return BigInt(bytesToHex(value))
+  }
+  const r = readInteger()
+  const s = readInteger()
+  return [r, s]
+}
@@ -0,0 +1,80 @@
+/**
+ * Perceptual Hash Utilities
+ * 64-bit DCT pHash that survives re-encoding and resizing, used to trace
+ * copies of a photo back to its attestation via findSimilarPhotos
+ */
+
+export const THUMBNAIL_SIZE = 32
+
+// Hamming distance searched when an exact photo hash isn't found
+export const SIMILARITY_DISTANCE = 7
+
+// Fixed-point DCT basis: round(4096 * cos((2x + 1) * u * PI / 64)) for u < 8
+const DCT_BASIS: number[][] = Array.from({ length: 8 }, (_, u) =>
+  Array.from({ length: THUMBNAIL_SIZE }, (_, x) =>
+    Math.round(4096 * Math.cos(((2 * x + 1) * u * Math.PI) / (2 * THUMBNAIL_SIZE)))
+  )
+)
+
+/**
+ * Downscale an image to a 32x32 grayscale thumbnail (row-major, one byte per pixel)
+ */
+export async function getGrayscaleThumbnail(dataUrl: string): Promise<Uint8Array> {
+  const image = new Image()
+  image.src = dataUrl
+  await image.decode()
+
+  const canvas = document.createElement('canvas')
+  canvas.width = THUMBNAIL_SIZE
+  canvas.height = THUMBNAIL_SIZE
+  const ctx = canvas.getContext('2d')
+  if (!ctx) throw new Error('Canvas 2D context unavailable')
+  ctx.drawImage(image, 0, 0, THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+
+  const { data } = ctx.getImageData(0, 0, THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+  const pixels = new Uint8Array(THUMBNAIL_SIZE * THUMBNAIL_SIZE)
+  for (let i = 0; i < pixels.length; i++) {
+    pixels[i] = Math.floor((299 * data[4 * i] + 587 * data[4 * i + 1] + 114 * data[4 * i + 2]) / 1000)
+  }
+  return pixels
+}
+
+/**
+ * pHash of a 32x32 grayscale thumbnail - mirrors `phash::compute` in the contract
+ * Bit 63 is the DC coefficient; each bit is set when the low-frequency
+ * coefficient is above the median of the 8x8 block
+ * Expected hashes of shared fixtures live in the `phash` tests of the contract
+ */
+export function perceptualHashFromThumbnail(pixels: Uint8Array): bigint {
+  if (pixels.length !== THUMBNAIL_SIZE * THUMBNAIL_SIZE) {
+    throw new Error(`Thumbnail must be ${THUMBNAIL_SIZE}x${THUMBNAIL_SIZE} grayscale`)
+  }
+
+  // Rows first, then columns; all values stay exact integers below 2^53
+  const rows: number[][] = []
+  for (let y = 0; y < THUMBNAIL_SIZE; y++) {
+    rows.push(DCT_BASIS.map(basis =>
+      basis.reduce((sum, c, x) => sum + c * pixels[y * THUMBNAIL_SIZE + x], 0)
+    ))
+  }
+  const coefficients: number[] = []
+  for (let v = 0; v < 8; v++) {
+    for (let u = 0; u < 8; u++) {
+      coefficients.push(DCT_BASIS[v].reduce((sum, c, y) => sum + c * rows[y][u], 0))
+    }
+  }
+
+  const sorted = [...coefficients].sort((a, b) => a - b)
+  const medianTimesTwo = sorted[31] + sorted[32]
+  return coefficients.reduce(
+    (hash, value) => (hash << 1n) | (2 * value > medianTimesTwo ? 1n : 0n),
+    0n
+  )
+}
+
+/**
+ * Compute the pHash of an image data URL
+ */
+export async function computePerceptualHash(dataUrl: string): Promise<bigint> {
+  return perceptualHashFromThumbnail(await getGrayscaleThumbnail(dataUrl))
+}
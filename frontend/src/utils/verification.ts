@@ -3,7 +3,8 @@
  * Generate verification links, badges, and Twitter-compatible content
  */
 
-import { APP_URL, VERIFIER_ADDRESS, PINATA_GATEWAY } from '../config'
+import type { PublicClient } from 'viem'
+import { APP_URL, VERIFIER_ADDRESS, PINATA_GATEWAY, GALLERY_ABI, MAX_PAGE_SIZE } from '../config'
 
 export interface VerificationData {
   photoHash: string
@@ -204,6 +205,29 @@ export function getAllLocalVerifications(): string[] {
   return JSON.parse(localStorage.getItem(listKey) || '[]')
 }
 
+/**
+ * Rebuild an owner's gallery from chain state by paging through the Gallery
+ * companion's getOwnerPhotos
+ * Works on any device, unlike getAllLocalVerifications
+ */
+export async function fetchOwnerPhotos(
+  client: PublicClient,
+  galleryAddress: `0x${string}`,
+  owner: `0x${string}`
+): Promise<string[]> {
+  const photos: string[] = []
+  for (let offset = 0n; ; offset += BigInt(MAX_PAGE_SIZE)) {
+    const page = await client.readContract({
+      address: galleryAddress,
+      abi: GALLERY_ABI,
+      functionName: 'getOwnerPhotos',
+      args: [owner, offset, BigInt(MAX_PAGE_SIZE)],
+    })
+    photos.push(...page.map(hash => hash.toString(16).padStart(64, '0')))
+    if (page.length < MAX_PAGE_SIZE) return photos
+  }
+}
+
 /**
  * Copy verification link to clipboard
  */
@@ -3,7 +3,7 @@
  * Generate and verify ZK proofs for photo ownership
  */
 
-import { keccak256, toHex, concat } from 'viem';
+import { keccak256, toHex, concat, encodePacked, stringToHex } from 'viem';
 
 /**
  * Generate a random secret for ZK commitment
@@ -60,35 +60,97 @@ export function verifyZKProofLocally(
 }
 
 /**
- * Advanced: Generate Circom/SnarkJS proof (placeholder)
- * 
- * For real implementation:
- * 1. Install: npm install snarkjs circomlib
- * 2. Create circuit in Circom
- * 3. Compile circuit to WASM
- * 4. Generate witness
- * 5. Create proof
- * 6. Verify on-chain
- */
-export async function generateSNARKProof(
-  photoHash: string,
-  secret: bigint
-): Promise<{
-  proof: any;
-  publicSignals: any;
-}> {
-  // This would use actual snarkjs library
-  console.warn('SNARK proof generation not implemented. Use circom + snarkjs for production.');
-  
-  // Placeholder return
-  return {
-    proof: {
-      pi_a: [],
-      pi_b: [],
-      pi_c: [],
-    },
-    publicSignals: [],
-  };
+ * Compute the digest a proof key signs to answer an ownership challenge
+ * Mirrors `ownership_challenge_digest` in the Stylus contract
+ *
+ * The verifier picks a fresh random challenge; the owner signs this digest
+ * with personal_sign (signMessage({ message: { raw: digest } })) and the
+ * contract checks the signature via `verifySignatureProof` - the signing key
+ * is never revealed, so the answer can't be reused for another challenge.
+ */
+export function computeOwnershipChallengeDigest(
+  chainId: number,
+  contractAddress: `0x${string}`,
+  photoHash: bigint,
+  challenge: `0x${string}`
+): `0x${string}` {
+  return keccak256(
+    encodePacked(
+      ['bytes', 'uint256', 'address', 'uint256', 'bytes32'],
+      [stringToHex('ArbiPic ownership proof'), BigInt(chainId), contractAddress, photoHash, challenge]
+    )
+  );
+}
+
+/**
+ * Digest an enrolled capture device signs for a photo taken at captureTime
+ * (unix seconds). The device signs it with personal_sign and `owner` submits
+ * the result through `verifyPhotoFromDevice` with the same zkCommitment.
+ */
+export function computeDeviceCaptureDigest(
+  chainId: number,
+  contractAddress: `0x${string}`,
+  owner: `0x${string}`,
+  photoHash: bigint,
+  zkCommitment: bigint,
+  captureTime: bigint
+): `0x${string}` {
+  return keccak256(
+    encodePacked(
+      ['bytes', 'uint256', 'address', 'address', 'uint256', 'uint256', 'uint256'],
+      [stringToHex('ArbiPic device capture'), BigInt(chainId), contractAddress, owner, photoHash, zkCommitment, captureTime]
+    )
+  );
+}
+
+/**
+ * Generate a random 32-byte challenge for signature-based ownership proofs
+ */
+export function generateChallenge(): `0x${string}` {
+  return toHex(generateSecret(), { size: 32 });
+}
+
+/**
+ * BN254 scalar field modulus - SNARK public inputs must be below it
+ */
+export const SNARK_SCALAR_FIELD =
+  21888242871839275222246405745257275088548364400416034343698204186575808495617n;
+
+/**
+ * Reduce a SHA-256 photo hash into the SNARK scalar field
+ * Matches the `photo_hash mod r` public input used by `verify_snark_proof`
+ */
+export function photoHashToField(photoHash: bigint): bigint {
+  return photoHash % SNARK_SCALAR_FIELD;
+}
+
+/**
+ * Reduce the contract's ownershipChallengeDigest into the SNARK scalar field
+ * Matches the `challenge` public input used by `verify_snark_proof`
+ */
+export function challengeDigestToField(digest: `0x${string}`): bigint {
+  return BigInt(digest) % SNARK_SCALAR_FIELD;
+}
+
+/**
+ * Flatten a snarkjs Groth16 proof into the uint256[8] layout expected by
+ * `verifySnarkProof`: pi_a (x, y) || pi_b (x_im, x_re, y_im, y_re) || pi_c (x, y)
+ */
+export function formatGroth16Proof(proof: {
+  pi_a: string[];
+  pi_b: string[][];
+  pi_c: string[];
+}): readonly [bigint, bigint, bigint, bigint, bigint, bigint, bigint, bigint] {
+  return [
+    BigInt(proof.pi_a[0]),
+    BigInt(proof.pi_a[1]),
+    BigInt(proof.pi_b[0][1]),
+    BigInt(proof.pi_b[0][0]),
+    BigInt(proof.pi_b[1][1]),
+    BigInt(proof.pi_b[1][0]),
+    BigInt(proof.pi_c[0]),
+    BigInt(proof.pi_c[1]),
+  ] as const;
 }
 
 /**
@@ -5,6 +5,16 @@ interface ImportMetaEnv {
   readonly VITE_PINATA_GATEWAY: string
   readonly VITE_CONTRACT_ADDRESS: string
   readonly VITE_RPC_URL: string
+  readonly VITE_RELAY_ADDRESS?: string
+  readonly VITE_BULK_ADDRESS?: string
+  readonly VITE_PROOFS_ADDRESS?: string
+  readonly VITE_SNARK_ADDRESS?: string
+  readonly VITE_GALLERY_ADDRESS?: string
+  readonly VITE_DEVICES_ADDRESS?: string
+  readonly VITE_METADATA_ADDRESS?: string
+  readonly VITE_PHASH_ADDRESS?: string
+  readonly VITE_TOKEN_ADDRESS?: string
+  readonly VITE_EAS_ADDRESS?: string
 }
 
 interface ImportMeta {
@@ -1,11 +1,19 @@
 #!/bin/bash
 
-# Quick check script for Stylus contract
+# Quick check script for the Stylus contracts
+# Checks the Verifier and every companion; `cargo stylus check` fails any
+# contract whose compressed WASM is over the 24 KB limit
 
 set -e
 
-echo "🔍 Checking Stylus contract..."
+RPC_URL=${RPC_URL:-https://sepolia-rollup.arbitrum.io/rpc}
+
 cd contracts
-cargo stylus check --endpoint https://sepolia-rollup.arbitrum.io/rpc
+CONTRACTS="arbipic-verifier $(ls companions | sed 's/^/arbipic-/')"
+
+for contract in $CONTRACTS; do
+    echo "🔍 Checking $contract..."
+    cargo stylus check --contract "$contract" --endpoint "$RPC_URL"
+done
 
-echo "✅ Contract check passed!"
+echo "✅ All contracts passed!"
@@ -1,7 +1,10 @@
 #!/bin/bash
 
 # ArbiPic Enhanced Contract Deployment Script
-# Deploy Stylus contract with full metadata support to Arbitrum Sepolia
+# Deploy the Verifier and its companion contracts to Arbitrum Sepolia
+# Companions take the Verifier's address in their constructor; the ones that
+# verify or move photos get COMPANION_ROLE and the hooks are added with setHook.
+# Needs Foundry's `cast` for the wiring calls
 
 set -e
 
@@ -20,45 +23,97 @@ if [ -z "$PRIVATE_KEY" ]; then
     exit 1
 fi
 
-cd contracts
+# Contract owner is bound by the constructor at deployment
+if [ -z "$OWNER_ADDRESS" ]; then
+    echo "❌ Error: OWNER_ADDRESS environment variable not set"
+    echo ""
+    echo "Usage:"
+    echo "  export OWNER_ADDRESS=\"0xYourAdminAddress\""
+    echo ""
+    exit 1
+fi
+
+RPC_URL=https://sepolia-rollup.arbitrum.io/rpc
 
-echo "🔍 Step 1: Checking contract..."
-cargo stylus check --endpoint https://sepolia-rollup.arbitrum.io/rpc
+# keccak256("COMPANION_ROLE")
+COMPANION_ROLE=0xe38cfb508fb0ea1f74eee16ff96be52d091242d49e11472293b68387d7ff69b4
+
+# Companions recording or moving photos on the Verifier, and Verifier hooks
+RECORDERS="relay bulk proofs devices metadata phash token"
+HOOKS="gallery devices proofs token eas"
+
+echo "🔍 Step 1: Checking contracts..."
+RPC_URL=$RPC_URL ./scripts/check.sh
+
+cd contracts
 
 echo ""
-echo "📦 Step 2: Deploying to Arbitrum Sepolia..."
+echo "📦 Step 2: Deploying the Verifier to Arbitrum Sepolia..."
 echo ""
 
 DEPLOYMENT_OUTPUT=$(cargo stylus deploy \
-    --endpoint https://sepolia-rollup.arbitrum.io/rpc \
-    --private-key $PRIVATE_KEY 2>&1)
+    --contract arbipic-verifier \
+    --endpoint $RPC_URL \
+    --private-key $PRIVATE_KEY \
+    --constructor-args $OWNER_ADDRESS 2>&1)
 
 echo "$DEPLOYMENT_OUTPUT"
 
 # Extract contract address from output
 CONTRACT_ADDRESS=$(echo "$DEPLOYMENT_OUTPUT" | grep -o "0x[a-fA-F0-9]\{40\}" | head -1)
 
-if [ -n "$CONTRACT_ADDRESS" ]; then
-    echo ""
-    echo "✅ Deployment successful!"
-    echo ""
-    echo "📋 Contract Details:"
-    echo "   Address: $CONTRACT_ADDRESS"
-    echo "   Network: Arbitrum Sepolia"
-    echo "   Explorer: https://sepolia.arbiscan.io/address/$CONTRACT_ADDRESS"
-    echo ""
-    echo "📝 Next Steps:"
-    echo "   1. Update frontend/src/config.ts with this address:"
-    echo "      export const VERIFIER_ADDRESS = '$CONTRACT_ADDRESS' as const"
-    echo ""
-    echo "   2. Initialize the contract:"
-    echo "      Call the init() function from your frontend"
-    echo ""
-    echo "   3. Start verifying photos!"
-    echo ""
-else
+if [ -z "$CONTRACT_ADDRESS" ]; then
     echo ""
     echo "❌ Deployment may have failed. Check output above."
     echo ""
     exit 1
 fi
+
+echo ""
+echo "🧩 Step 3: Deploying companions..."
+COMPANION_ENV=""
+for companion in $(ls companions); do
+    echo ""
+    echo "   $companion"
+    OUTPUT=$(cargo stylus deploy \
+        --contract arbipic-$companion \
+        --endpoint $RPC_URL \
+        --private-key $PRIVATE_KEY \
+        --constructor-args $CONTRACT_ADDRESS 2>&1)
+    echo "$OUTPUT"
+    ADDRESS=$(echo "$OUTPUT" | grep -o "0x[a-fA-F0-9]\{40\}" | head -1)
+    if [ -z "$ADDRESS" ]; then
+        echo "❌ Deploying $companion may have failed. Check output above."
+        exit 1
+    fi
+    COMPANION_ENV="$COMPANION_ENV\n      VITE_$(echo $companion | tr a-z A-Z)_ADDRESS=$ADDRESS"
+
+    # The owner holds every role, so it may grant COMPANION_ROLE and add hooks
+    if [[ " $RECORDERS " == *" $companion "* ]]; then
+        cast send $CONTRACT_ADDRESS "grantRole(bytes32,address)" $COMPANION_ROLE $ADDRESS \
+            --rpc-url $RPC_URL --private-key $PRIVATE_KEY > /dev/null
+    fi
+    if [[ " $HOOKS " == *" $companion "* ]]; then
+        cast send $CONTRACT_ADDRESS "setHook(address,bool)" $ADDRESS true \
+            --rpc-url $RPC_URL --private-key $PRIVATE_KEY > /dev/null
+    fi
+done
+
+echo ""
+echo "✅ Deployment successful!"
+echo ""
+echo "📋 Contract Details:"
+echo "   Address: $CONTRACT_ADDRESS"
+echo "   Network: Arbitrum Sepolia"
+echo "   Explorer: https://sepolia.arbiscan.io/address/$CONTRACT_ADDRESS"
+echo ""
+echo "📝 Next Steps:"
+echo "   1. Update frontend/src/config.ts with this address:"
+echo "      export const VERIFIER_ADDRESS = '$CONTRACT_ADDRESS' as const"
+echo ""
+echo "   2. Add the companions to frontend/.env:"
+echo -e "$COMPANION_ENV"
+echo ""
+echo "   3. Start verifying photos!"
+echo "      (Owner $OWNER_ADDRESS was set by the constructor, no init() needed)"
+echo ""
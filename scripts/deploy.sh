@@ -1,19 +1,17 @@
 #!/bin/bash
 
 # ArbiPic Contract Deployment Script
-# Deploy Stylus contract to Arbitrum Sepolia
+# Deploy the Verifier Stylus contract to Arbitrum Sepolia
+# deploy-enhanced.sh also deploys and wires up the companion contracts
 
 set -e
 
 echo "🚀 Building Stylus Contract..."
 cd contracts
-cargo stylus check
+cargo stylus check --contract arbipic-verifier
 
 echo "📦 Exporting ABI..."
-cargo build --release --features export-abi
-
-echo "🔍 Estimating gas..."
-cargo stylus estimate-gas --endpoint https://sepolia-rollup.arbitrum.io/rpc
+cargo build --release --features export-abi -p arbipic-verifier
 
 echo "📤 Deploying to Arbitrum Sepolia..."
 echo "Make sure you have PRIVATE_KEY environment variable set"
@@ -23,10 +21,26 @@ if [ -z "$PRIVATE_KEY" ]; then
     exit 1
 fi
 
+if [ -z "$OWNER_ADDRESS" ]; then
+    echo "❌ Error: OWNER_ADDRESS environment variable not set (contract owner passed to the constructor)"
+    exit 1
+fi
+
+echo "🔍 Estimating gas..."
+cargo stylus deploy \
+    --contract arbipic-verifier \
+    --endpoint https://sepolia-rollup.arbitrum.io/rpc \
+    --private-key $PRIVATE_KEY \
+    --constructor-args $OWNER_ADDRESS \
+    --estimate-gas
+
 cargo stylus deploy \
+    --contract arbipic-verifier \
     --endpoint https://sepolia-rollup.arbitrum.io/rpc \
     --private-key $PRIVATE_KEY \
+    --constructor-args $OWNER_ADDRESS \
     --no-verify
 
 echo "✅ Deployment complete!"
 echo "📝 Update the contract address in frontend/src/config.ts"
+echo "🧩 Companion contracts are deployed by scripts/deploy-enhanced.sh"